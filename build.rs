@@ -0,0 +1,75 @@
+// generate one golden test per year, day, part and input file under data/
+//
+// expected answers live next to each input, e.g. data/2022/day9-sample2.answers
+// for data/2022/day9-sample2.txt, see src/golden.rs for the format
+use std::env;
+use std::fs;
+use std::path::Path;
+
+include!("src/golden/sidecar.rs");
+
+fn read_sidecar(path: &Path) -> Vec<Entry> {
+    parse_sidecar(&fs::read_to_string(path).unwrap_or_default())
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=data");
+    println!("cargo:rerun-if-changed=src/golden/sidecar.rs");
+
+    let mut tests = String::new();
+    let mut years: Vec<_> = fs::read_dir("data")
+        .unwrap()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().is_dir())
+        .collect();
+    years.sort_by_key(|e| e.file_name());
+
+    for year in years {
+        let year_name = year.file_name().to_string_lossy().into_owned();
+        if year_name.parse::<u32>().is_err() {
+            continue;
+        }
+        println!("cargo:rerun-if-changed=data/{}", year_name);
+
+        let mut inputs: Vec<_> = fs::read_dir(year.path())
+            .unwrap()
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| p.extension().is_some_and(|x| x == "txt"))
+            .collect();
+        inputs.sort();
+
+        for input in inputs {
+            let stem = input.file_stem().unwrap().to_string_lossy().into_owned();
+            if !stem.starts_with("day") {
+                continue;
+            }
+            let sidecar = input.with_extension("answers");
+            let entries = read_sidecar(&sidecar);
+            for part in 1..=2 {
+                let entry = entries.iter().find(|e| e.part == part);
+                // "-" means the part doesn't apply to this input
+                if entry.is_some_and(|e| e.answer == "-") {
+                    continue;
+                }
+                let ignore = if entry.is_some_and(|e| e.slow) {
+                    "#[ignore = \"slow\"]\n"
+                } else {
+                    ""
+                };
+                tests.push_str(&format!(
+                    "#[test]\n{}fn y{}_{}_part{}() {{\n    check(\"{}\", {});\n}}\n\n",
+                    ignore,
+                    year_name,
+                    stem.replace('-', "_"),
+                    part,
+                    input.to_string_lossy().replace('\\', "/"),
+                    part
+                ));
+            }
+        }
+    }
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("golden_tests.rs");
+    fs::write(out, tests).unwrap();
+}
@@ -0,0 +1,108 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// a thin wrapper around the system allocator that keeps track of
+// how many bytes are live, the peak of that and how many allocations happened
+//
+// only installed as the global allocator with `--features alloc-stats`
+pub struct CountingAlloc;
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+static BASELINE: AtomicUsize = AtomicUsize::new(0);
+static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, Clone, Copy)]
+pub struct Stats {
+    pub peak_bytes: usize,
+    pub allocations: usize,
+}
+
+#[inline]
+fn grow(size: usize) {
+    let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(current, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            COUNT.fetch_add(1, Ordering::Relaxed);
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            COUNT.fetch_add(1, Ordering::Relaxed);
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    // a realloc is counted as one allocation
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            COUNT.fetch_add(1, Ordering::Relaxed);
+            if new_size > layout.size() {
+                grow(new_size - layout.size());
+            } else {
+                CURRENT.fetch_sub(layout.size() - new_size, Ordering::Relaxed);
+            }
+        }
+        new_ptr
+    }
+}
+
+/// start a new measurement from what is currently live on the heap
+pub fn reset() {
+    let current = CURRENT.load(Ordering::Relaxed);
+    BASELINE.store(current, Ordering::Relaxed);
+    PEAK.store(current, Ordering::Relaxed);
+    COUNT.store(0, Ordering::Relaxed);
+}
+
+/// peak is reported relative to the heap usage at the last `reset`
+pub fn stats() -> Stats {
+    let baseline = BASELINE.load(Ordering::Relaxed);
+    Stats {
+        peak_bytes: PEAK.load(Ordering::Relaxed).saturating_sub(baseline),
+        allocations: COUNT.load(Ordering::Relaxed),
+    }
+}
+
+pub fn format_bytes(bytes: usize) -> String {
+    let units = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < units.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} {}", bytes, units[0])
+    } else {
+        format!("{:.1} {}", value, units[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!("512 B", format_bytes(512));
+        assert_eq!("1.5 KiB", format_bytes(1536));
+        assert_eq!("3.0 MiB", format_bytes(3 * 1024 * 1024));
+    }
+}
@@ -0,0 +1,90 @@
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use crate::puzzle::Puzzle;
+
+pub const DEFAULT_DIR: &str = "target/aoc-cache";
+
+// FNV-1a, stable across runs and toolchains unlike `DefaultHasher`
+fn fnv1a(chunks: &[&[u8]]) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for chunk in chunks {
+        for b in chunk.iter() {
+            hash ^= *b as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+        // keep ("ab", "c") and ("a", "bc") apart
+        hash ^= 0xff;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+/// answers on disk, keyed by the input contents and the solver's `VERSION`
+///
+/// a solver bumps its `VERSION` whenever its answers could change, which
+/// makes every older entry unreachable
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Cache { dir: dir.into() }
+    }
+
+    fn path(&self, puzzle: &Puzzle, version: &str, input: &[u8]) -> PathBuf {
+        let hash = fnv1a(&[version.as_bytes(), input]);
+        self.dir.join(puzzle.year.to_string()).join(format!(
+            "day{}-part{}-{:016x}",
+            puzzle.day, puzzle.part, hash
+        ))
+    }
+
+    pub fn get(&self, puzzle: &Puzzle, version: &str, input: &[u8]) -> Option<String> {
+        fs::read_to_string(self.path(puzzle, version, input)).ok()
+    }
+
+    pub fn put(
+        &self,
+        puzzle: &Puzzle,
+        version: &str,
+        input: &[u8],
+        answer: &str,
+    ) -> io::Result<()> {
+        let path = self.path(puzzle, version, input);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, answer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fnv1a() {
+        assert_ne!(fnv1a(&[b"ab", b"c"]), fnv1a(&[b"a", b"bc"]));
+        assert_eq!(fnv1a(&[b"1", b"input"]), fnv1a(&[b"1", b"input"]));
+    }
+
+    #[test]
+    fn test_cache() {
+        let dir = std::env::temp_dir().join(format!("aoc-cache-{}", std::process::id()));
+        let cache = Cache::new(&dir);
+        let p = Puzzle::new(2022, 1, 1);
+
+        assert_eq!(None, cache.get(&p, "1", b"100\n"));
+        cache.put(&p, "1", b"100\n", "100").unwrap();
+        assert_eq!(Some("100".to_owned()), cache.get(&p, "1", b"100\n"));
+        // a new solver version or different input misses
+        assert_eq!(None, cache.get(&p, "2", b"100\n"));
+        assert_eq!(None, cache.get(&p, "1", b"200\n"));
+        assert_eq!(None, cache.get(&Puzzle::new(2022, 1, 2), "1", b"100\n"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,207 @@
+// golden answers for every input file under data/
+//
+// each `data/<year>/dayN[-suffix].txt` has a sidecar `dayN[-suffix].answers`:
+//
+//     # part[ slow]: answer
+//     1: 24000
+//     2 slow: 45000
+//
+// an answer of `-` means the part doesn't apply to that input (e.g. day15's
+// sample uses a different row), `slow` parts are skipped unless asked for.
+// build.rs turns every (input, part) into its own test.
+use std::fs;
+use std::panic;
+use std::path::{Path, PathBuf};
+
+use crate::puzzle::{self, Puzzle};
+
+mod sidecar;
+
+pub use sidecar::Entry;
+
+pub fn sidecar_path(input: &Path) -> PathBuf {
+    input.with_extension("answers")
+}
+
+pub fn read_sidecar(path: &Path) -> Vec<Entry> {
+    sidecar::parse_sidecar(&fs::read_to_string(path).unwrap_or_default())
+}
+
+fn write_sidecar(path: &Path, entries: &[Entry]) -> std::io::Result<()> {
+    let mut cnt = String::from("# part[ slow]: answer\n");
+    for e in entries {
+        let flag = if e.slow { " slow" } else { "" };
+        cnt.push_str(&format!("{}{}: {}\n", e.part, flag, e.answer));
+    }
+    fs::write(path, cnt)
+}
+
+// "data/2022/day9-sample2.txt" belongs to year 2022, day 9
+pub fn year_and_day(input: &Path) -> Option<(u32, u32)> {
+    let year = input.parent()?.file_name()?.to_str()?.parse().ok()?;
+    let stem = input.file_stem()?.to_str()?.strip_prefix("day")?;
+    let day = stem.split('-').next()?.parse().ok()?;
+    Some((year, day))
+}
+
+/// every puzzle input under data/, sorted
+pub fn inputs() -> Vec<PathBuf> {
+    let mut inputs = Vec::new();
+    let years = match fs::read_dir("data") {
+        Ok(years) => years,
+        Err(_) => return inputs,
+    };
+    for year in years.filter_map(|e| e.ok()) {
+        if let Ok(files) = fs::read_dir(year.path()) {
+            for f in files.filter_map(|e| e.ok()) {
+                let path = f.path();
+                if path.extension().is_some_and(|x| x == "txt") && year_and_day(&path).is_some() {
+                    inputs.push(path);
+                }
+            }
+        }
+    }
+    inputs.sort();
+    inputs
+}
+
+// a solver panicking on an input it wasn't meant for shouldn't stop the run
+fn solve(puzzle: &Puzzle, input: &Path) -> Option<String> {
+    let filepath = input.to_string_lossy().into_owned();
+    panic::catch_unwind(|| puzzle::solve(puzzle, &filepath)).unwrap_or(None)
+}
+
+/// used by the generated tests
+#[cfg(test)]
+pub fn check(input: &str, part: u32) {
+    let input = Path::new(input);
+    let (year, day) = year_and_day(input).expect("input should be data/<year>/dayN*.txt");
+    let puzzle = Puzzle::new(year, day, part);
+    let entries = read_sidecar(&sidecar_path(input));
+    let expected = match entries.iter().find(|e| e.part == part) {
+        Some(e) => &e.answer,
+        None => panic!(
+            "no answer for part {} of {}, record it with `cargo run --release -- golden --accept`",
+            part,
+            input.display()
+        ),
+    };
+    let filepath = input.to_string_lossy();
+    let answer = puzzle::solve(&puzzle, &filepath).expect("puzzle should be handled");
+    assert_eq!(expected, &answer, "{} on {}", puzzle, input.display());
+}
+
+/// check every input against its sidecar, `accept` records new and changed
+/// answers instead of reporting them. returns whether everything matched
+pub fn run(accept: bool, include_slow: bool) -> bool {
+    let mut all_ok = true;
+    for input in inputs() {
+        let (year, day) = year_and_day(&input).unwrap();
+        let sidecar = sidecar_path(&input);
+        let mut entries = read_sidecar(&sidecar);
+        let mut changed = false;
+
+        for part in 1..=2 {
+            let puzzle = Puzzle::new(year, day, part);
+            let existing = entries.iter().position(|e| e.part == part);
+            if let Some(idx) = existing {
+                let e = &entries[idx];
+                if e.answer == "-" || (e.slow && !include_slow) {
+                    continue;
+                }
+            }
+
+            let answer = match solve(&puzzle, &input) {
+                Some(answer) => answer,
+                None => {
+                    println!("{} {}: FAILED to solve", puzzle, input.display());
+                    all_ok = false;
+                    continue;
+                }
+            };
+
+            match existing {
+                Some(idx) if entries[idx].answer == answer => {
+                    println!("{} {}: ok", puzzle, input.display());
+                }
+                Some(idx) => {
+                    println!(
+                        "{} {}: expected {} got {}",
+                        puzzle,
+                        input.display(),
+                        entries[idx].answer,
+                        answer
+                    );
+                    if accept {
+                        entries[idx].answer = answer;
+                        changed = true;
+                    } else {
+                        all_ok = false;
+                    }
+                }
+                None => {
+                    println!("{} {}: new answer {}", puzzle, input.display(), answer);
+                    if accept {
+                        entries.push(Entry {
+                            part,
+                            answer,
+                            slow: false,
+                        });
+                        changed = true;
+                    } else {
+                        all_ok = false;
+                    }
+                }
+            }
+        }
+
+        if changed {
+            entries.sort_by_key(|e| e.part);
+            if let Err(e) = write_sidecar(&sidecar, &entries) {
+                println!("failed to write {}: {}", sidecar.display(), e);
+                all_ok = false;
+            }
+        }
+    }
+    all_ok
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_year_and_day() {
+        assert_eq!(
+            Some((2022, 9)),
+            year_and_day(Path::new("data/2022/day9-sample2.txt"))
+        );
+        assert_eq!(None, year_and_day(Path::new("data/2022/notes.txt")));
+    }
+
+    #[test]
+    fn test_sidecar_roundtrip() {
+        let path = std::env::temp_dir().join(format!("aoc-golden-{}.answers", std::process::id()));
+        let entries = vec![
+            Entry {
+                part: 1,
+                answer: "-".into(),
+                slow: false,
+            },
+            Entry {
+                part: 2,
+                answer: "(14, 11)".into(),
+                slow: true,
+            },
+        ];
+        write_sidecar(&path, &entries).unwrap();
+        assert_eq!(
+            "# part[ slow]: answer\n1: -\n2 slow: (14, 11)\n",
+            fs::read_to_string(&path).unwrap()
+        );
+        assert_eq!(entries, read_sidecar(&path));
+        fs::remove_file(&path).unwrap();
+    }
+
+    include!(concat!(env!("OUT_DIR"), "/golden_tests.rs"));
+}
@@ -0,0 +1,30 @@
+// the sidecar format, shared with build.rs through `include!`, so this file
+// can't depend on anything else in the crate
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub part: u32,
+    pub answer: String,
+    pub slow: bool,
+}
+
+// every `part[ slow]: answer` line, comments and anything else are skipped
+pub fn parse_sidecar(cnt: &str) -> Vec<Entry> {
+    let mut entries = Vec::new();
+    for line in cnt.lines() {
+        if line.starts_with('#') {
+            continue;
+        }
+        if let Some((key, answer)) = line.split_once(": ") {
+            let mut words = key.split_whitespace();
+            if let Some(Ok(part)) = words.next().map(|p| p.parse()) {
+                entries.push(Entry {
+                    part,
+                    answer: answer.to_owned(),
+                    slow: words.any(|w| w == "slow"),
+                });
+            }
+        }
+    }
+    entries
+}
@@ -0,0 +1,129 @@
+// closed intervals over integers, shared by the days working with ranges
+use std::fmt;
+
+/// integers an interval can be made of
+pub trait Step: Copy + Ord {
+    // the next value, saturating at the maximum
+    fn succ(self) -> Self;
+    // how many values `start..=end` holds
+    fn count(start: Self, end: Self) -> u64;
+}
+
+macro_rules! impl_step {
+    ($($t:ty),*) => {
+        $(impl Step for $t {
+            fn succ(self) -> Self {
+                self.saturating_add(1)
+            }
+
+            fn count(start: Self, end: Self) -> u64 {
+                (end as i128 - start as i128 + 1) as u64
+            }
+        })*
+    };
+}
+
+impl_step!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// `start..=end`, never empty
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Step> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        assert!(start <= end, "interval start is after its end");
+        Interval { start, end }
+    }
+
+    pub fn contains(&self, x: T) -> bool {
+        self.start <= x && x <= self.end
+    }
+
+    pub fn contains_interval(&self, other: &Interval<T>) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn overlaps(&self, other: &Interval<T>) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    pub fn intersection(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        self.overlaps(other)
+            .then(|| Interval::new(self.start.max(other.start), self.end.min(other.end)))
+    }
+
+    /// one interval covering both, if they overlap or touch
+    pub fn union(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        let touches = self.start <= other.end.succ() && other.start <= self.end.succ();
+        touches.then(|| Interval::new(self.start.min(other.start), self.end.max(other.end)))
+    }
+
+    pub fn len(&self) -> u64 {
+        T::count(self.start, self.end)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+/// the same values as `intervals`, as sorted intervals that neither overlap
+/// nor touch
+pub fn merge<T: Step>(intervals: impl IntoIterator<Item = Interval<T>>) -> Vec<Interval<T>> {
+    let mut sorted: Vec<_> = intervals.into_iter().collect();
+    sorted.sort_unstable_by_key(|i| i.start);
+    let mut merged: Vec<Interval<T>> = Vec::with_capacity(sorted.len());
+    for i in sorted {
+        match merged.last_mut() {
+            Some(last) => match last.union(&i) {
+                Some(u) => *last = u,
+                None => merged.push(i),
+            },
+            None => merged.push(i),
+        }
+    }
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interval() {
+        let a = Interval::new(2, 8);
+        let b = Interval::new(3, 7);
+        let c = Interval::new(9, 12);
+        assert!(a.contains(2) && a.contains(8) && !a.contains(9));
+        assert!(a.contains_interval(&b) && !b.contains_interval(&a));
+        assert!(a.overlaps(&b) && !a.overlaps(&c));
+        assert!(Interval::new(6, 6).overlaps(&Interval::new(4, 6)));
+        assert_eq!(Some(b), a.intersection(&b));
+        assert_eq!(None, a.intersection(&c));
+        // 2-8 and 9-12 have no gap in between
+        assert_eq!(Some(Interval::new(2, 12)), a.union(&c));
+        assert_eq!(None, b.union(&c));
+        assert_eq!(7, a.len());
+        assert_eq!(4294967296, Interval::new(0u32, u32::MAX).len());
+        assert_eq!("-3-4", Interval::new(-3, 4).to_string());
+    }
+
+    #[test]
+    fn test_merge() {
+        let merged = merge([
+            Interval::new(12, 12),
+            Interval::new(-2, 2),
+            Interval::new(2, 14),
+            Interval::new(16, 24),
+            Interval::new(14, 18),
+            Interval::new(30, 31),
+        ]);
+        assert_eq!(vec![Interval::new(-2, 24), Interval::new(30, 31)], merged);
+        assert_eq!(Vec::<Interval<u32>>::new(), merge([]));
+    }
+}
@@ -0,0 +1,317 @@
+// validate the shape of an input file before handing it to a solver
+//
+// every day declares a `GRAMMAR`: the patterns each line has to match, plus an
+// optional check for rules that span more than one line.
+//
+// patterns are matched against the whole line:
+//
+//   {uint} {int} {word} {name}  digits, optionally signed digits, letters, non-space run
+//   [a-z] [^ ]                  one byte out of (or not in) a class
+//   ( ... )                     a group
+//   ? * +                       after a byte, class or group: optional, any, one or more
+//   \x                          a literal x
+use std::collections::BTreeSet;
+use std::fmt;
+
+pub struct Grammar {
+    pub lines: &'static [&'static str],
+    pub check: Option<fn(&str) -> Vec<Issue>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Issue {
+    // 1-based, None for problems with the file as a whole
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl Issue {
+    pub fn at(line: usize, message: impl Into<String>) -> Self {
+        Issue {
+            line: Some(line),
+            message: message.into(),
+        }
+    }
+
+    pub fn file(message: impl Into<String>) -> Self {
+        Issue {
+            line: None,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "line {}: {}", line, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Rep {
+    One,
+    Optional,
+    Any,
+    Many,
+}
+
+#[derive(Debug, Clone)]
+enum Atom {
+    Byte(u8),
+    Class {
+        ranges: Vec<(u8, u8)>,
+        negated: bool,
+    },
+    Group(Vec<Token>),
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    atom: Atom,
+    rep: Rep,
+}
+
+fn class(ranges: &[(u8, u8)], rep: Rep) -> Token {
+    Token {
+        atom: Atom::Class {
+            ranges: ranges.to_vec(),
+            negated: false,
+        },
+        rep,
+    }
+}
+
+fn parse_class(pattern: &[u8], pos: &mut usize) -> Atom {
+    let mut negated = false;
+    if pattern.get(*pos) == Some(&b'^') {
+        negated = true;
+        *pos += 1;
+    }
+    let mut ranges = Vec::new();
+    loop {
+        let b = match pattern.get(*pos) {
+            Some(b']') => {
+                *pos += 1;
+                break;
+            }
+            Some(b'\\') => {
+                *pos += 1;
+                pattern[*pos]
+            }
+            Some(b) => *b,
+            None => panic!("unterminated class in pattern"),
+        };
+        *pos += 1;
+        if pattern.get(*pos) == Some(&b'-') && pattern.get(*pos + 1).is_some_and(|b| *b != b']') {
+            ranges.push((b, pattern[*pos + 1]));
+            *pos += 2;
+        } else {
+            ranges.push((b, b));
+        }
+    }
+    Atom::Class { ranges, negated }
+}
+
+fn parse_pattern(pattern: &[u8], pos: &mut usize) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    while let Some(b) = pattern.get(*pos) {
+        *pos += 1;
+        let atom = match b {
+            b')' => break,
+            b'(' => Atom::Group(parse_pattern(pattern, pos)),
+            b'[' => parse_class(pattern, pos),
+            b'\\' => {
+                *pos += 1;
+                Atom::Byte(pattern[*pos - 1])
+            }
+            b'{' => {
+                let end = *pos + pattern[*pos..].iter().position(|b| *b == b'}').unwrap();
+                let name = &pattern[*pos..end];
+                *pos = end + 1;
+                match name {
+                    b"uint" => tokens.push(class(&[(b'0', b'9')], Rep::Many)),
+                    b"int" => {
+                        tokens.push(class(&[(b'-', b'-')], Rep::Optional));
+                        tokens.push(class(&[(b'0', b'9')], Rep::Many));
+                    }
+                    b"word" => tokens.push(class(&[(b'a', b'z'), (b'A', b'Z')], Rep::Many)),
+                    b"name" => tokens.push(Token {
+                        atom: Atom::Class {
+                            ranges: vec![(b' ', b' ')],
+                            negated: true,
+                        },
+                        rep: Rep::Many,
+                    }),
+                    _ => panic!("unknown placeholder in pattern"),
+                }
+                continue;
+            }
+            b => Atom::Byte(*b),
+        };
+        let rep = match pattern.get(*pos) {
+            Some(b'?') => Rep::Optional,
+            Some(b'*') => Rep::Any,
+            Some(b'+') => Rep::Many,
+            _ => Rep::One,
+        };
+        if rep != Rep::One {
+            *pos += 1;
+        }
+        tokens.push(Token { atom, rep });
+    }
+    tokens
+}
+
+// every position a single occurrence of `atom` starting at `pos` can end at
+fn atom_ends(atom: &Atom, s: &[u8], pos: usize) -> BTreeSet<usize> {
+    let mut ends = BTreeSet::new();
+    match atom {
+        Atom::Byte(b) => {
+            if s.get(pos) == Some(b) {
+                ends.insert(pos + 1);
+            }
+        }
+        Atom::Class { ranges, negated } => {
+            if let Some(b) = s.get(pos) {
+                let hit = ranges.iter().any(|(lo, hi)| lo <= b && b <= hi);
+                if hit != *negated {
+                    ends.insert(pos + 1);
+                }
+            }
+        }
+        Atom::Group(tokens) => ends = seq_ends(tokens, s, pos),
+    }
+    ends
+}
+
+// every position the token sequence starting at `pos` can end at
+fn seq_ends(tokens: &[Token], s: &[u8], pos: usize) -> BTreeSet<usize> {
+    let (token, rest) = match tokens.split_first() {
+        Some(x) => x,
+        None => return BTreeSet::from([pos]),
+    };
+
+    let mut reached = BTreeSet::new();
+    match token.rep {
+        Rep::One => reached = atom_ends(&token.atom, s, pos),
+        Rep::Optional => {
+            reached = atom_ends(&token.atom, s, pos);
+            reached.insert(pos);
+        }
+        Rep::Any | Rep::Many => {
+            let mut frontier = atom_ends(&token.atom, s, pos);
+            while !frontier.is_empty() {
+                let mut next = BTreeSet::new();
+                for p in frontier {
+                    if reached.insert(p) {
+                        next.extend(atom_ends(&token.atom, s, p).into_iter().filter(|x| *x > p));
+                    }
+                }
+                frontier = next;
+            }
+            if token.rep == Rep::Any {
+                reached.insert(pos);
+            }
+        }
+    }
+
+    let mut ends = BTreeSet::new();
+    for p in reached {
+        ends.extend(seq_ends(rest, s, p));
+    }
+    ends
+}
+
+fn matches(pattern: &str, line: &str) -> bool {
+    let tokens = parse_pattern(pattern.as_bytes(), &mut 0);
+    seq_ends(&tokens, line.as_bytes(), 0).contains(&line.len())
+}
+
+/// every problem with `input`, in line order
+pub fn lint(grammar: &Grammar, input: &str) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    for (idx, line) in input.lines().enumerate() {
+        if !grammar.lines.iter().any(|p| matches(p, line)) {
+            issues.push(Issue::at(idx + 1, format!("unexpected {:?}", line)));
+        }
+    }
+    if let Some(check) = grammar.check {
+        issues.extend(check(input));
+    }
+    issues.sort_by_key(|i| i.line);
+    issues.dedup();
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::golden;
+    use crate::puzzle;
+
+    #[test]
+    fn test_matches() {
+        assert!(matches(
+            "move {uint} from {uint} to {uint}",
+            "move 1 from 2 to 3"
+        ));
+        assert!(!matches(
+            "move {uint} from {uint} to {uint}",
+            "move 1 from 2 to"
+        ));
+        assert!(matches("addx {int}", "addx -12"));
+        assert!(matches("", ""));
+        assert!(!matches("", " "));
+        assert!(matches("[ABC] [XYZ]", "B Z"));
+        assert!(!matches("[ABC] [XYZ]", "D Z"));
+        assert!(matches(
+            "{uint},{uint}( -> {uint},{uint})*",
+            "498,4 -> 498,6 -> 496,6"
+        ));
+        assert!(!matches(
+            "{uint},{uint}( -> {uint},{uint})*",
+            "498,4 -> 498,6 ->"
+        ));
+        assert!(matches("tunnels? leads? to", "tunnel leads to"));
+        assert!(matches(
+            "[a-z]+: [a-z]+ [+\\-*/] [a-z]+",
+            "root: pppw + sjmn"
+        ));
+        assert!(matches("\\$ cd {name}", "$ cd a.b"));
+        assert!(!matches("\\$ cd {name}", "$ cd a b"));
+    }
+
+    #[test]
+    fn test_lint_reports_every_line() {
+        let grammar = Grammar {
+            lines: &["[ABC] [XYZ]"],
+            check: None,
+        };
+        assert_eq!(
+            vec![
+                Issue::at(2, "unexpected \"A W\""),
+                Issue::at(4, "unexpected \"\""),
+            ],
+            lint(&grammar, "A X\nA W\nC Z\n\n")
+        );
+    }
+
+    // everything we already have solutions for should pass its own grammar
+    #[test]
+    fn test_data_files() {
+        for input in golden::inputs() {
+            let (year, day) = golden::year_and_day(&input).unwrap();
+            let grammar = puzzle::grammar(year, day).expect("every day declares a grammar");
+            let cnt = std::fs::read_to_string(&input).unwrap();
+            assert_eq!(
+                Vec::<Issue>::new(),
+                lint(grammar, &cnt),
+                "{}",
+                input.display()
+            );
+        }
+    }
+}
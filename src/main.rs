@@ -1,76 +1,213 @@
 #![feature(test)]
 
 use std::env;
+use std::fs;
+use std::net::TcpListener;
+use std::time::{Duration, Instant};
 
-mod day1;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod day19;
-mod day2;
-mod day20;
-mod day21;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
+use cache::Cache;
+use puzzle::Puzzle;
+
+#[cfg(feature = "alloc-stats")]
+mod alloc;
+
+mod cache;
+mod golden;
+mod interval;
+mod lint;
+mod puzzle;
+mod server;
+mod submit;
+mod y2022;
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static GLOBAL: alloc::CountingAlloc = alloc::CountingAlloc;
+
+fn format_duration(d: Duration) -> String {
+    let micros = d.as_micros();
+    if micros < 1000 {
+        format!("{}µs", micros)
+    } else if micros < 1_000_000 {
+        format!("{:.2}ms", micros as f64 / 1000.0)
+    } else {
+        format!("{:.2}s", d.as_secs_f64())
+    }
+}
+
+// print the answer to stdout, timing (and memory use if enabled) to stderr
+// with `fresh` the answer is always computed, and then replaces the cached one
+fn run(puzzle: &Puzzle, filepath: &str, cache: &Cache, fresh: bool) {
+    // the cache only kicks in for solvers that declare a version
+    let cached = puzzle::version(puzzle).zip(fs::read(filepath).ok());
+
+    #[cfg(feature = "alloc-stats")]
+    alloc::reset();
+    let start = Instant::now();
+    let mut hit = false;
+    let answer = match &cached {
+        Some((version, input)) if !fresh => match cache.get(puzzle, version, input) {
+            Some(answer) => {
+                hit = true;
+                Some(answer)
+            }
+            None => puzzle::solve(puzzle, filepath),
+        },
+        _ => puzzle::solve(puzzle, filepath),
+    };
+    let elapsed = start.elapsed();
+
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            println!("{} not handled", puzzle);
+            return;
+        }
+    };
+
+    if let (false, Some((version, input))) = (hit, &cached) {
+        if let Err(e) = cache.put(puzzle, version, input, &answer) {
+            eprintln!("failed to cache {}: {}", puzzle, e);
+        }
+    }
+
+    let mut report = format!(
+        "{:>9} took {}",
+        puzzle.to_string(),
+        format_duration(elapsed)
+    );
+    if hit {
+        report.push_str(" (cached)");
+    }
+    #[cfg(feature = "alloc-stats")]
+    {
+        let stats = alloc::stats();
+        report.push_str(&format!(
+            ", peak heap {}, {} allocations",
+            alloc::format_bytes(stats.peak_bytes),
+            stats.allocations
+        ));
+    }
+    println!("{}", answer);
+    eprintln!("{}", report);
+}
+
+// look up the value following a flag, e.g. `--port 8080`
+fn flag_value<'a>(args: &'a [String], flag: &str) -> Option<&'a str> {
+    args.iter()
+        .position(|a| a == flag)
+        .and_then(|i| args.get(i + 1))
+        .map(|v| &v[..])
+}
+
+// "5" for a day of the default year, or "2022/5"
+fn parse_day(s: &str) -> Result<(u32, u32), Box<dyn std::error::Error + 'static>> {
+    Ok(match s.split_once('/') {
+        Some((year, day)) => (year.parse()?, day.parse()?),
+        None => (puzzle::DEFAULT_YEAR, s.parse()?),
+    })
+}
 
 fn main() -> Result<(), Box<dyn std::error::Error + 'static>> {
-    let args: Vec<String> = env::args().collect();
+    let mut args: Vec<String> = env::args().collect();
+    // `--no-cache` recomputes every answer, and refreshes the cache with it
+    let no_cache = args.iter().any(|a| a == "--no-cache");
+    args.retain(|a| a != "--no-cache");
+    let cache = Cache::new(cache::DEFAULT_DIR);
+
     let which_puzzle = &args[1];
     match &which_puzzle[..] {
-        "1.1" => println!("{}", day1::part1("data/day1.txt")),
-        "1.2" => println!("{}", day1::part2("data/day1.txt")),
-        "2.1" => println!("{}", day2::part1()),
-        "2.2" => println!("{}", day2::part2()),
-        "3.1" => println!("{}", day3::part1()),
-        "3.2" => println!("{}", day3::part2()),
-        "4.1" => println!("{}", day4::part1()),
-        "4.2" => println!("{}", day4::part2()),
-        "5.1" => println!("{}", day5::part1()),
-        "5.2" => println!("{}", day5::part2()),
-        "6.1" => println!("{}", day6::part1()),
-        "6.2" => println!("{}", day6::part2()),
-        "7.1" => println!("{}", day7::part1("data/day7.txt")),
-        "7.2" => println!("{}", day7::part2("data/day7.txt")),
-        "8.1" => println!("{}", day8::part1("data/day8.txt")),
-        "8.2" => println!("{}", day8::part2("data/day8.txt")),
-        "9.1" => println!("{}", day9::part1("data/day9.txt")),
-        "9.2" => println!("{}", day9::part2("data/day9.txt")),
-        "10.1" => println!("{}", day10::part1("data/day10-sample.txt")),
-        "10.2" => println!("{}", day10::part2("data/day10.txt")),
-        "11.1" => println!("{}", day11::part1("data/day11.txt")),
-        "11.2" => println!("{}", day11::part2("data/day11.txt")),
-        "12.1" => println!("{}", day12::part1("data/day12.txt")),
-        "12.2" => println!("{}", day12::part2("data/day12.txt")),
-        "13.1" => println!("{}", day13::part1("data/day13.txt")),
-        "13.2" => println!("{}", day13::part2("data/day13.txt")),
-        "14.1" => println!("{}", day14::part1("data/day14.txt")),
-        "14.2" => println!("{}", day14::part2("data/day14.txt")),
-        "15.1" => println!("{}", day15::part1("data/day15.txt", 2000000)),
-        "15.2" => println!("{:?}", day15::part2("data/day15.txt", 4000000)),
-        "16.1" => println!("{}", day16::part1("data/day16-sample.txt")),
-        "16.2" => println!("{:?}", day16::part2("data/day16.txt")),
-        "17.1" => println!("{:?}", day17::part1("data/day17.txt")),
-        "17.2" => println!("{:?}", day17::part2("data/day17-sample.txt")),
-        "18.1" => println!("{:?}", day18::part1("data/day18-sample.txt")),
-        "18.2" => println!("{:?}", day18::part2("data/day18.txt")),
-        "19.1" => println!("{:?}", day19::part1("data/day19-sample.txt")),
-        "19.2" => println!("{:?}", day19::part2("data/day19-sample.txt")),
-        "20.1" => println!("{:?}", day20::solve(1, "data/day20.txt")),
-        "20.2" => println!("{:?}", day20::solve(2, "data/day20.txt")),
-        "21.1" => println!("{:?}", day21::solve("data/day21.txt").0),
-        "21.2" => println!("{:?}", day21::solve("data/day21.txt").1),
-        _ => println!("{} not handled", which_puzzle),
+        "all" => {
+            // optionally only the puzzles of one year, e.g. `all 2022`
+            let year: Option<u32> = args.get(2).map(|y| y.parse()).transpose()?;
+            for (p, filepath) in puzzle::all() {
+                if year.is_none() || year == Some(p.year) {
+                    run(&p, filepath, &cache, no_cache);
+                }
+            }
+        }
+        "serve" => {
+            let port: u16 = flag_value(&args, "--port").unwrap_or("8022").parse()?;
+            let timeout: u64 = flag_value(&args, "--timeout").unwrap_or("30").parse()?;
+            let listener = TcpListener::bind(("127.0.0.1", port))?;
+            eprintln!("listening on http://{}", listener.local_addr()?);
+            server::serve(listener, Duration::from_secs(timeout));
+        }
+        "golden" => {
+            let accept = args.iter().any(|a| a == "--accept");
+            let include_slow = args.iter().any(|a| a == "--slow");
+            if !golden::run(accept, include_slow) {
+                return Err("some answers don't match, rerun with --accept to record them".into());
+            }
+        }
+        "lint" => {
+            // `lint 5 file` or `lint 2022/5 file`
+            let usage = "usage: lint <day> <file>";
+            let which_day = args.get(2).ok_or(usage)?;
+            let filepath = args.get(3).ok_or(usage)?;
+            let (year, day) = parse_day(which_day)?;
+            let grammar =
+                puzzle::grammar(year, day).ok_or(format!("no grammar for {}/{}", year, day))?;
+            let issues = lint::lint(grammar, &fs::read_to_string(filepath)?);
+            for issue in issues.iter() {
+                match issue.line {
+                    Some(line) => println!("{}:{}: {}", filepath, line, issue.message),
+                    None => println!("{}: {}", filepath, issue.message),
+                }
+            }
+            if !issues.is_empty() {
+                return Err(format!("{} problem(s) found", issues.len()).into());
+            }
+        }
+        "report" => {
+            // `report 8 --ppm forest.ppm` also saves a picture, for the days
+            // that can draw one
+            let ppm = flag_value(&args, "--ppm").map(|p| p.to_owned());
+            let args: Vec<&String> = match args.iter().position(|a| a == "--ppm") {
+                Some(i) => args[..i].iter().chain(args.iter().skip(i + 2)).collect(),
+                None => args.iter().collect(),
+            };
+            let (year, day) = parse_day(
+                args.get(2)
+                    .ok_or("usage: report <day> [file] [--ppm out]")?,
+            )?;
+            let filepath = args
+                .get(3)
+                .map(|f| &f[..])
+                .or(puzzle::default_input(&Puzzle::new(year, day, 1)))
+                .ok_or(format!("no input for {}/{}", year, day))?;
+            let report = puzzle::report(year, day, filepath)
+                .ok_or(format!("no report for {}/{}", year, day))?;
+            print!("{}", report);
+            if let Some(out) = ppm {
+                let image = puzzle::ppm(year, day, filepath)
+                    .ok_or(format!("no picture for {}/{}", year, day))?;
+                fs::write(&out, image)?;
+                eprintln!("saved {}", out);
+            }
+        }
+        "submit" => {
+            let p: Puzzle = args
+                .get(2)
+                .ok_or("usage: submit <day.part> [file]")?
+                .parse()?;
+            let filepath = args
+                .get(3)
+                .map(|f| &f[..])
+                .or(puzzle::default_input(&p))
+                .ok_or(format!("{} not handled", p))?;
+            let submitter = submit::Submitter::from_env()?;
+            let answer = puzzle::solve(&p, filepath).ok_or(format!("{} not handled", p))?;
+            eprintln!("submitting {} for {}", answer, p);
+            println!("{}", submitter.submit(&p, &answer)?);
+        }
+        _ => {
+            let p: Puzzle = which_puzzle.parse()?;
+            match args.get(2).map(|f| &f[..]).or(puzzle::default_input(&p)) {
+                Some(filepath) => run(&p, filepath, &cache, no_cache),
+                None => println!("{} not handled", p),
+            }
+        }
     }
     Ok(())
 }
@@ -0,0 +1,152 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::lint::Grammar;
+
+// bare "day.part" ids like "3.2" refer to this year
+pub const DEFAULT_YEAR: u32 = 2022;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Puzzle {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParsePuzzleError(String);
+
+impl fmt::Display for ParsePuzzleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid puzzle id {:?}, expected year/day/part or day.part",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for ParsePuzzleError {}
+
+impl Puzzle {
+    pub fn new(year: u32, day: u32, part: u32) -> Self {
+        Puzzle { year, day, part }
+    }
+}
+
+/// parse "2022/3/2", or "3.2" for a puzzle of the default year
+impl FromStr for Puzzle {
+    type Err = ParsePuzzleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParsePuzzleError(s.to_owned());
+        let numbers: Vec<u32> = if s.contains('/') {
+            s.split('/').map(|x| x.parse()).collect::<Result<_, _>>()
+        } else {
+            s.split('.').map(|x| x.parse()).collect::<Result<_, _>>()
+        }
+        .map_err(|_| err())?;
+
+        match (s.contains('/'), &numbers[..]) {
+            (true, [year, day, part]) => Ok(Puzzle::new(*year, *day, *part)),
+            (false, [day, part]) => Ok(Puzzle::new(DEFAULT_YEAR, *day, *part)),
+            _ => Err(err()),
+        }
+    }
+}
+
+impl fmt::Display for Puzzle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}/{}", self.year, self.day, self.part)
+    }
+}
+
+/// every puzzle this binary knows about, with the input it runs on by default
+pub fn all() -> Vec<(Puzzle, &'static str)> {
+    crate::y2022::PUZZLES
+        .iter()
+        .map(|(day, part, filepath)| (Puzzle::new(2022, *day, *part), *filepath))
+        .collect()
+}
+
+pub fn default_input(puzzle: &Puzzle) -> Option<&'static str> {
+    all()
+        .into_iter()
+        .find(|(p, _)| p == puzzle)
+        .map(|(_, filepath)| filepath)
+}
+
+pub fn solve(puzzle: &Puzzle, filepath: &str) -> Option<String> {
+    match puzzle.year {
+        2022 => crate::y2022::solve(puzzle.day, puzzle.part, filepath),
+        _ => None,
+    }
+}
+
+pub fn grammar(year: u32, day: u32) -> Option<&'static Grammar> {
+    match year {
+        2022 => crate::y2022::grammar(day),
+        _ => None,
+    }
+}
+
+pub fn version(puzzle: &Puzzle) -> Option<&'static str> {
+    match puzzle.year {
+        2022 => crate::y2022::version(puzzle.day, puzzle.part),
+        _ => None,
+    }
+}
+
+/// whether what `solve` returns is the actual answer, and not a placeholder
+/// for one that's only printed
+pub fn has_value(puzzle: &Puzzle) -> bool {
+    match puzzle.year {
+        2022 => !crate::y2022::printed_only(puzzle.day, puzzle.part),
+        _ => true,
+    }
+}
+
+pub fn report(year: u32, day: u32, filepath: &str) -> Option<String> {
+    match year {
+        2022 => crate::y2022::report(day, filepath),
+        _ => None,
+    }
+}
+
+pub fn ppm(year: u32, day: u32, filepath: &str) -> Option<String> {
+    match year {
+        2022 => crate::y2022::ppm(day, filepath),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Ok(Puzzle::new(2022, 3, 2)), "3.2".parse());
+        assert_eq!(Ok(Puzzle::new(2021, 14, 1)), "2021/14/1".parse());
+        assert!("3".parse::<Puzzle>().is_err());
+        assert!("2022/3".parse::<Puzzle>().is_err());
+        assert!("3.x".parse::<Puzzle>().is_err());
+        assert_eq!("2022/3/2", Puzzle::new(2022, 3, 2).to_string());
+    }
+
+    #[test]
+    fn test_default_input() {
+        let p = Puzzle::new(2022, 1, 1);
+        assert_eq!(Some("data/2022/day1.txt"), default_input(&p));
+        assert_eq!(None, default_input(&Puzzle::new(2015, 1, 1)));
+    }
+
+    #[test]
+    fn test_version() {
+        assert!(version(&Puzzle::new(2022, 10, 1)).is_some());
+        // only printed, never cached
+        assert_eq!(None, version(&Puzzle::new(2022, 10, 2)));
+        assert!(has_value(&Puzzle::new(2022, 10, 1)));
+        assert!(!has_value(&Puzzle::new(2022, 10, 2)));
+    }
+}
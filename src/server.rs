@@ -0,0 +1,372 @@
+use std::fs;
+use std::io::{BufRead, BufReader, ErrorKind, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::puzzle::{self, Puzzle, DEFAULT_YEAR};
+
+// puzzle inputs are small, anything bigger than this is most likely a mistake
+const MAX_BODY_SIZE: usize = 16 * 1024 * 1024;
+// the request line and all the headers together
+const MAX_HEAD_SIZE: usize = 8 * 1024;
+
+static REQUEST_ID: AtomicUsize = AtomicUsize::new(0);
+
+struct Response {
+    status: u16,
+    body: String,
+}
+
+impl Response {
+    fn error(status: u16, message: &str) -> Self {
+        Response {
+            status,
+            body: format!("{{\"error\":\"{}\"}}", escape_json(message)),
+        }
+    }
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        408 => "Request Timeout",
+        413 => "Payload Too Large",
+        431 => "Request Header Fields Too Large",
+        500 => "Internal Server Error",
+        504 => "Gateway Timeout",
+        _ => "",
+    }
+}
+
+fn escape_json(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+// parse "/year/2022/day/3/part/2", or "/day/3/part/2" for the default year
+fn parse_route(path: &str) -> Option<Puzzle> {
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    match segments[..] {
+        ["year", year, "day", day, "part", part] => Some(Puzzle::new(
+            year.parse().ok()?,
+            day.parse().ok()?,
+            part.parse().ok()?,
+        )),
+        ["day", day, "part", part] => Some(Puzzle::new(
+            DEFAULT_YEAR,
+            day.parse().ok()?,
+            part.parse().ok()?,
+        )),
+        _ => None,
+    }
+}
+
+// the input file only lives as long as the solver needs it
+struct InputFile(PathBuf);
+
+impl InputFile {
+    fn create(body: &[u8]) -> std::io::Result<Self> {
+        let id = REQUEST_ID.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("aoc-{}-{}.txt", std::process::id(), id));
+        fs::write(&path, body)?;
+        Ok(InputFile(path))
+    }
+}
+
+impl Drop for InputFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+fn solve_with_timeout(puzzle: Puzzle, body: &[u8], timeout: Duration) -> Response {
+    if puzzle::default_input(&puzzle).is_none() {
+        return Response::error(404, &format!("{} not handled", puzzle));
+    }
+    let input = match InputFile::create(body) {
+        Ok(input) => input,
+        Err(e) => return Response::error(500, &e.to_string()),
+    };
+
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let start = Instant::now();
+        let filepath = input.0.to_string_lossy().into_owned();
+        let answer = puzzle::solve(&puzzle, &filepath);
+        let _ = tx.send((answer, start.elapsed()));
+    });
+
+    // threads can't be killed, a solver that times out keeps running in the
+    // background until it finishes on its own
+    match rx.recv_timeout(timeout) {
+        Ok((Some(answer), elapsed)) => Response {
+            status: 200,
+            body: format!(
+                "{{\"year\":{},\"day\":{},\"part\":{},\"answer\":\"{}\",\"elapsed_ms\":{:.3}}}",
+                puzzle.year,
+                puzzle.day,
+                puzzle.part,
+                escape_json(&answer),
+                elapsed.as_secs_f64() * 1000.0
+            ),
+        },
+        Ok((None, _)) => Response::error(404, "puzzle not handled"),
+        Err(mpsc::RecvTimeoutError::Timeout) => Response::error(504, "solver timed out"),
+        // the sender is dropped without sending when the solver panics
+        Err(mpsc::RecvTimeoutError::Disconnected) => {
+            Response::error(500, "solver failed on this input")
+        }
+    }
+}
+
+// a client gets `timeout` to send the whole request, not `timeout` per read
+struct Deadline(Instant);
+
+impl Deadline {
+    fn arm(&self, stream: &TcpStream) -> std::io::Result<()> {
+        let left = self.0.saturating_duration_since(Instant::now());
+        if left.is_zero() {
+            return Err(ErrorKind::TimedOut.into());
+        }
+        stream.set_read_timeout(Some(left))
+    }
+}
+
+// one line of the request head, `None` once the head is over its size
+fn read_head_line(
+    reader: &mut BufReader<&mut TcpStream>,
+    deadline: &Deadline,
+    budget: &mut usize,
+) -> std::io::Result<Option<String>> {
+    deadline.arm(reader.get_ref())?;
+    let mut line = String::new();
+    let read = reader
+        .by_ref()
+        .take(*budget as u64 + 1)
+        .read_line(&mut line)?;
+    if read > *budget {
+        return Ok(None);
+    }
+    *budget -= read;
+    Ok(Some(line))
+}
+
+fn handle(stream: &mut TcpStream, timeout: Duration) -> std::io::Result<Response> {
+    let deadline = Deadline(Instant::now() + timeout);
+    let mut reader = BufReader::new(stream);
+    let mut budget = MAX_HEAD_SIZE;
+    let too_large = || Ok(Response::error(431, "request head is too large"));
+
+    let request_line = match read_head_line(&mut reader, &deadline, &mut budget)? {
+        Some(line) => line,
+        None => return too_large(),
+    };
+    let mut parts = request_line.split_whitespace();
+    let (method, path) = match (parts.next(), parts.next()) {
+        (Some(method), Some(path)) => (method.to_owned(), path.to_owned()),
+        _ => return Ok(Response::error(400, "malformed request line")),
+    };
+
+    let mut content_length = 0;
+    loop {
+        let line = match read_head_line(&mut reader, &deadline, &mut budget)? {
+            Some(line) => line,
+            None => return too_large(),
+        };
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = match value.trim().parse() {
+                    Ok(n) => n,
+                    Err(_) => return Ok(Response::error(400, "invalid content-length")),
+                };
+            }
+        }
+    }
+
+    let puzzle = match parse_route(&path) {
+        Some(puzzle) => puzzle,
+        None => {
+            return Ok(Response::error(
+                404,
+                "expected /year/{y}/day/{n}/part/{p} or /day/{n}/part/{p}",
+            ))
+        }
+    };
+    if method != "POST" {
+        return Ok(Response::error(405, "only POST is supported"));
+    }
+    if content_length > MAX_BODY_SIZE {
+        return Ok(Response::error(413, "input is too large"));
+    }
+
+    let mut body = vec![0; content_length];
+    let mut filled = 0;
+    while filled < content_length {
+        deadline.arm(reader.get_ref())?;
+        match reader.read(&mut body[filled..])? {
+            0 => {
+                return Ok(Response::error(
+                    400,
+                    "request body is shorter than its content-length",
+                ))
+            }
+            n => filled += n,
+        }
+    }
+
+    Ok(solve_with_timeout(puzzle, &body, timeout))
+}
+
+fn handle_connection(mut stream: TcpStream, timeout: Duration) {
+    let response = match handle(&mut stream, timeout) {
+        Ok(response) => response,
+        // a read timeout shows up as either of these, depending on the platform
+        Err(e) if matches!(e.kind(), ErrorKind::TimedOut | ErrorKind::WouldBlock) => {
+            Response::error(408, "request took too long to send")
+        }
+        Err(e) => Response::error(400, &e.to_string()),
+    };
+    let _ = stream.set_write_timeout(Some(timeout));
+    let _ = write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        reason(response.status),
+        response.body.len(),
+        response.body
+    );
+}
+
+/// serve `POST /year/{y}/day/{n}/part/{p}` (or `/day/{n}/part/{p}` for the
+/// default year) with the puzzle input as the request body
+///
+/// every connection is handled on its own thread, and gets `timeout` both to
+/// send its request and for the solver to answer it
+pub fn serve(listener: TcpListener, timeout: Duration) {
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => {
+                thread::spawn(move || handle_connection(stream, timeout));
+            }
+            Err(e) => eprintln!("failed to accept connection: {}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn start(timeout: Duration) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap().to_string();
+        thread::spawn(move || serve(listener, timeout));
+        addr
+    }
+
+    fn request(addr: &str, method: &str, path: &str, body: &str) -> String {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        write!(
+            stream,
+            "{} {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{}",
+            method,
+            path,
+            body.len(),
+            body
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    }
+
+    #[test]
+    fn test_parse_route() {
+        assert_eq!(Some(Puzzle::new(2022, 3, 2)), parse_route("/day/3/part/2"));
+        assert_eq!(
+            Some(Puzzle::new(2021, 3, 2)),
+            parse_route("/year/2021/day/3/part/2")
+        );
+        assert_eq!(None, parse_route("/day/3"));
+        assert_eq!(None, parse_route("/day/x/part/2"));
+    }
+
+    #[test]
+    fn test_serve() {
+        let addr = start(Duration::from_secs(10));
+        let input = fs::read_to_string("data/2022/day1-sample.txt").unwrap();
+
+        let handles: Vec<_> = (1..=2)
+            .map(|part| {
+                let addr = addr.clone();
+                let input = input.clone();
+                thread::spawn(move || {
+                    request(&addr, "POST", &format!("/day/1/part/{}", part), &input)
+                })
+            })
+            .collect();
+        let responses: Vec<String> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+        assert!(responses[0].starts_with("HTTP/1.1 200 OK"));
+        assert!(responses[0].contains("\"year\":2022,\"day\":1,\"part\":1,\"answer\":\"24000\""));
+        assert!(responses[1].contains("\"answer\":\"45000\""));
+
+        assert!(request(&addr, "POST", "/day/99/part/1", "").starts_with("HTTP/1.1 404"));
+        assert!(request(&addr, "GET", "/day/1/part/1", "").starts_with("HTTP/1.1 405"));
+    }
+
+    #[test]
+    fn test_timeout() {
+        let addr = start(Duration::from_millis(1));
+        let input = fs::read_to_string("data/2022/day14.txt").unwrap();
+        let response = request(&addr, "POST", "/day/14/part/2", &input);
+        assert!(response.starts_with("HTTP/1.1 504"));
+    }
+
+    #[test]
+    fn test_slow_client() {
+        let addr = start(Duration::from_millis(200));
+
+        // promises more body than it sends, then waits
+        let mut stream = TcpStream::connect(&addr).unwrap();
+        write!(
+            stream,
+            "POST /day/1/part/1 HTTP/1.1\r\nContent-Length: 100\r\n\r\n1000\n"
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 408"));
+
+        // a header line that never ends, cut one byte over the limit so the
+        // server has read all of it when it answers
+        let mut stream = TcpStream::connect(&addr).unwrap();
+        let mut head = b"POST /day/1/part/1 HTTP/1.1\r\nX-Long: ".to_vec();
+        head.resize(MAX_HEAD_SIZE + 1, b'a');
+        stream.write_all(&head).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 431"));
+    }
+}
@@ -0,0 +1,543 @@
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Read, Write};
+use std::net::TcpStream;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::puzzle::{self, Puzzle};
+
+// where the answer is posted to, `{year}` and `{day}` are filled in
+pub const URL_ENV: &str = "AOC_SUBMIT_URL";
+pub const SESSION_ENV: &str = "AOC_SESSION";
+pub const LOG_ENV: &str = "AOC_SUBMIT_LOG";
+pub const DEFAULT_LOG: &str = ".aoc/submissions.log";
+
+/// anything that can POST a form and hand back the status and body
+///
+/// swapped out in tests so no real endpoint is hit
+pub trait HttpClient {
+    fn post_form(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+        body: &str,
+    ) -> io::Result<(u16, String)>;
+}
+
+/// bare bones HTTP/1.1 client on top of `TcpStream`
+///
+/// there is no TLS, so the endpoint has to be plain http (e.g. a local proxy)
+pub struct TcpClient;
+
+// split "http://host:port/path" into ("host:port", "path")
+fn split_url(url: &str) -> io::Result<(&str, &str)> {
+    let invalid = || {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("unsupported url {}", url),
+        )
+    };
+    let rest = url.strip_prefix("http://").ok_or_else(invalid)?;
+    match rest.find('/') {
+        Some(idx) => Ok((&rest[..idx], &rest[idx..])),
+        None => Ok((rest, "/")),
+    }
+}
+
+impl HttpClient for TcpClient {
+    fn post_form(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+        body: &str,
+    ) -> io::Result<(u16, String)> {
+        let (host, path) = split_url(url)?;
+        let addr = if host.contains(':') {
+            host.to_owned()
+        } else {
+            format!("{}:80", host)
+        };
+        let mut stream = TcpStream::connect(addr)?;
+        stream.set_read_timeout(Some(Duration::from_secs(30)))?;
+
+        let mut request = format!(
+            "POST {} HTTP/1.1\r\nHost: {}\r\nContent-Type: application/x-www-form-urlencoded\r\nContent-Length: {}\r\nConnection: close\r\n",
+            path,
+            host,
+            body.len()
+        );
+        for (name, value) in headers {
+            request.push_str(&format!("{}: {}\r\n", name, value));
+        }
+        request.push_str("\r\n");
+        request.push_str(body);
+        stream.write_all(request.as_bytes())?;
+
+        let mut response = Vec::new();
+        stream.read_to_end(&mut response)?;
+        let response = String::from_utf8_lossy(&response);
+
+        let status = response
+            .split_whitespace()
+            .nth(1)
+            .and_then(|s| s.parse().ok())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "malformed status line"))?;
+        let body = match response.find("\r\n\r\n") {
+            Some(idx) => response[idx + 4..].to_owned(),
+            None => String::new(),
+        };
+        Ok((status, body))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    RateLimited,
+    AlreadySolved,
+    Unknown,
+}
+
+impl Outcome {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Outcome::Right => "right",
+            Outcome::Wrong => "wrong",
+            Outcome::TooHigh => "too-high",
+            Outcome::TooLow => "too-low",
+            Outcome::RateLimited => "rate-limited",
+            Outcome::AlreadySolved => "already-solved",
+            Outcome::Unknown => "unknown",
+        }
+    }
+
+    fn from_str(s: &str) -> Option<Self> {
+        let o = match s {
+            "right" => Outcome::Right,
+            "wrong" => Outcome::Wrong,
+            "too-high" => Outcome::TooHigh,
+            "too-low" => Outcome::TooLow,
+            "rate-limited" => Outcome::RateLimited,
+            "already-solved" => Outcome::AlreadySolved,
+            "unknown" => Outcome::Unknown,
+            _ => return None,
+        };
+        Some(o)
+    }
+
+    fn is_wrong(&self) -> bool {
+        matches!(self, Outcome::Wrong | Outcome::TooHigh | Outcome::TooLow)
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+// "You have 4m 32s left to wait" or "please wait 5 minutes before trying again"
+fn parse_wait(text: &str) -> Option<Duration> {
+    if let Some(end) = text.find("left to wait") {
+        let start = text[..end].rfind("You have")? + "You have".len();
+        let mut secs = 0;
+        for part in text[start..end].split_whitespace() {
+            let (n, unit) = part.split_at(part.len() - 1);
+            let n: u64 = n.parse().ok()?;
+            secs += match unit {
+                "h" => n * 3600,
+                "m" => n * 60,
+                "s" => n,
+                _ => return None,
+            };
+        }
+        return Some(Duration::from_secs(secs));
+    }
+    let start = text.find("wait ")? + "wait ".len();
+    let mut words = text[start..].split_whitespace();
+    let n = match words.next()? {
+        "one" => 1,
+        n => n.parse().ok()?,
+    };
+    match words.next()? {
+        w if w.starts_with("minute") => Some(Duration::from_secs(n * 60)),
+        w if w.starts_with("second") => Some(Duration::from_secs(n)),
+        _ => None,
+    }
+}
+
+/// classify the page returned after submitting, plus how long to wait before
+/// the next submission if the page says so
+pub fn parse_response(body: &str) -> (Outcome, Option<Duration>) {
+    let outcome = if body.contains("That's the right answer") {
+        Outcome::Right
+    } else if body.contains("You gave an answer too recently") {
+        Outcome::RateLimited
+    } else if body.contains("your answer is too high") {
+        Outcome::TooHigh
+    } else if body.contains("your answer is too low") {
+        Outcome::TooLow
+    } else if body.contains("That's not the right answer") {
+        Outcome::Wrong
+    } else if body.contains("You don't seem to be solving the right level") {
+        Outcome::AlreadySolved
+    } else {
+        Outcome::Unknown
+    };
+    let wait = match outcome {
+        Outcome::Right | Outcome::AlreadySolved => None,
+        _ => parse_wait(body),
+    };
+    (outcome, wait)
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    Config(String),
+    KnownWrong(Outcome),
+    AlreadySolved,
+    Cooldown(Duration),
+    Http(io::Error),
+}
+
+impl fmt::Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmitError::Config(s) => write!(f, "{}", s),
+            SubmitError::KnownWrong(o) => {
+                write!(f, "refusing to resubmit, this answer is known to be {}", o)
+            }
+            SubmitError::AlreadySolved => write!(f, "refusing to submit, puzzle is already solved"),
+            SubmitError::Cooldown(d) => {
+                write!(f, "still cooling down, try again in {}s", d.as_secs())
+            }
+            SubmitError::Http(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for SubmitError {}
+
+impl From<io::Error> for SubmitError {
+    fn from(e: io::Error) -> Self {
+        SubmitError::Http(e)
+    }
+}
+
+#[derive(Debug)]
+struct LogEntry {
+    at: u64,
+    puzzle: Puzzle,
+    answer: String,
+    outcome: Outcome,
+    wait: u64,
+}
+
+// one tab separated line per submission:
+// unix time, puzzle, answer, outcome, seconds to wait afterwards
+fn parse_log_line(line: &str) -> Option<LogEntry> {
+    let mut fields = line.split('\t');
+    Some(LogEntry {
+        at: fields.next()?.parse().ok()?,
+        puzzle: fields.next()?.parse().ok()?,
+        answer: fields.next()?.to_owned(),
+        outcome: Outcome::from_str(fields.next()?)?,
+        wait: fields.next()?.parse().ok()?,
+    })
+}
+
+fn url_encode(s: &str) -> String {
+    let mut out = String::new();
+    for b in s.bytes() {
+        match b {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                out.push(b as char)
+            }
+            _ => out.push_str(&format!("%{:02X}", b)),
+        }
+    }
+    out
+}
+
+pub struct Submitter<C: HttpClient> {
+    pub client: C,
+    pub url_template: String,
+    pub session: Option<String>,
+    pub log_path: PathBuf,
+}
+
+impl Submitter<TcpClient> {
+    /// configure from `AOC_SUBMIT_URL`, `AOC_SESSION` and `AOC_SUBMIT_LOG`
+    pub fn from_env() -> Result<Self, SubmitError> {
+        let url_template = std::env::var(URL_ENV).map_err(|_| {
+            SubmitError::Config(format!(
+                "{} is not set, e.g. http://localhost:8080/{{year}}/day/{{day}}/answer",
+                URL_ENV
+            ))
+        })?;
+        Ok(Submitter {
+            client: TcpClient,
+            url_template,
+            session: std::env::var(SESSION_ENV).ok(),
+            log_path: std::env::var(LOG_ENV)
+                .unwrap_or_else(|_| DEFAULT_LOG.to_owned())
+                .into(),
+        })
+    }
+}
+
+impl<C: HttpClient> Submitter<C> {
+    fn read_log(&self) -> Vec<LogEntry> {
+        match fs::read_to_string(&self.log_path) {
+            Ok(cnt) => cnt.lines().filter_map(parse_log_line).collect(),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    fn append_log(&self, entry: &LogEntry) -> io::Result<()> {
+        if let Some(dir) = self.log_path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.log_path)?;
+        writeln!(
+            file,
+            "{}\t{}\t{}\t{}\t{}",
+            entry.at, entry.puzzle, entry.answer, entry.outcome, entry.wait
+        )
+    }
+
+    // what the log already tells us about submitting `answer` at `now`
+    fn check(
+        &self,
+        log: &[LogEntry],
+        puzzle: &Puzzle,
+        answer: &str,
+        now: u64,
+    ) -> Result<(), SubmitError> {
+        // cooldowns apply to the whole account, not just this puzzle
+        if let Some(last) = log.last() {
+            if last.at + last.wait > now {
+                return Err(SubmitError::Cooldown(Duration::from_secs(
+                    last.at + last.wait - now,
+                )));
+            }
+        }
+
+        let value: Option<i64> = answer.parse().ok();
+        for e in log.iter().filter(|e| e.puzzle == *puzzle) {
+            if e.outcome == Outcome::Right || e.outcome == Outcome::AlreadySolved {
+                return Err(SubmitError::AlreadySolved);
+            }
+            if e.outcome.is_wrong() && e.answer == answer {
+                return Err(SubmitError::KnownWrong(e.outcome.clone()));
+            }
+            // a bound we already ran into also rules out everything past it
+            if let (Some(v), Ok(bound)) = (value, e.answer.parse::<i64>()) {
+                if (e.outcome == Outcome::TooHigh && v >= bound)
+                    || (e.outcome == Outcome::TooLow && v <= bound)
+                {
+                    return Err(SubmitError::KnownWrong(e.outcome.clone()));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn submit(&self, puzzle: &Puzzle, answer: &str) -> Result<Outcome, SubmitError> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        self.submit_at(puzzle, answer, now)
+    }
+
+    pub fn submit_at(
+        &self,
+        puzzle: &Puzzle,
+        answer: &str,
+        now: u64,
+    ) -> Result<Outcome, SubmitError> {
+        if !puzzle::has_value(puzzle) {
+            return Err(SubmitError::Config(format!(
+                "{} is only printed, read it off and submit it by hand",
+                puzzle
+            )));
+        }
+        let answer = answer.trim();
+        if answer.is_empty() || answer.contains(char::is_whitespace) {
+            return Err(SubmitError::Config(format!(
+                "{:?} is not a valid answer",
+                answer
+            )));
+        }
+        self.check(&self.read_log(), puzzle, answer, now)?;
+
+        let url = self
+            .url_template
+            .replace("{year}", &puzzle.year.to_string())
+            .replace("{day}", &puzzle.day.to_string());
+        let body = format!("level={}&answer={}", puzzle.part, url_encode(answer));
+        let cookie = self.session.as_ref().map(|s| format!("session={}", s));
+        let mut headers = Vec::new();
+        if let Some(cookie) = &cookie {
+            headers.push(("Cookie", &cookie[..]));
+        }
+
+        let (status, page) = self.client.post_form(&url, &headers, &body)?;
+        let (outcome, wait) = parse_response(&page);
+        if status != 200 && outcome == Outcome::Unknown {
+            return Err(SubmitError::Http(io::Error::other(format!(
+                "endpoint responded with status {}",
+                status
+            ))));
+        }
+
+        self.append_log(&LogEntry {
+            at: now,
+            puzzle: *puzzle,
+            answer: answer.to_owned(),
+            outcome: outcome.clone(),
+            wait: wait.map_or(0, |d| d.as_secs()),
+        })?;
+        Ok(outcome)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    // answers every request with the next canned page and remembers what was posted
+    fn stub_server(pages: Vec<&'static str>) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap().to_string();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let seen = Arc::clone(&requests);
+        thread::spawn(move || {
+            for (stream, page) in listener.incoming().zip(pages) {
+                let mut stream = stream.unwrap();
+                let mut buf = [0; 4096];
+                let n = stream.read(&mut buf).unwrap();
+                seen.lock()
+                    .unwrap()
+                    .push(String::from_utf8_lossy(&buf[..n]).into_owned());
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{}",
+                    page.len(),
+                    page
+                )
+                .unwrap();
+            }
+        });
+        (addr, requests)
+    }
+
+    fn submitter(addr: &str, name: &str) -> Submitter<TcpClient> {
+        let log_path =
+            std::env::temp_dir().join(format!("aoc-submit-{}-{}.log", std::process::id(), name));
+        let _ = fs::remove_file(&log_path);
+        Submitter {
+            client: TcpClient,
+            url_template: format!("http://{}/{{year}}/day/{{day}}/answer", addr),
+            session: Some("abc".to_owned()),
+            log_path,
+        }
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(
+            (Outcome::Right, None),
+            parse_response("<p>That's the right answer! You are one gold star closer.</p>")
+        );
+        assert_eq!(
+            (Outcome::TooHigh, Some(Duration::from_secs(60))),
+            parse_response("That's not the right answer; your answer is too high. Please wait one minute before trying again.")
+        );
+        assert_eq!(
+            (Outcome::Wrong, Some(Duration::from_secs(300))),
+            parse_response(
+                "That's not the right answer. please wait 5 minutes before trying again."
+            )
+        );
+        assert_eq!(
+            (Outcome::RateLimited, Some(Duration::from_secs(272))),
+            parse_response("You gave an answer too recently. You have 4m 32s left to wait.")
+        );
+        assert_eq!(
+            (Outcome::AlreadySolved, None),
+            parse_response("You don't seem to be solving the right level.")
+        );
+    }
+
+    #[test]
+    fn test_submit() {
+        let (addr, requests) = stub_server(vec![
+            "That's not the right answer; your answer is too low. Please wait one minute before trying again.",
+            "That's the right answer!",
+        ]);
+        let s = submitter(&addr, "submit");
+        let p = Puzzle::new(2022, 1, 2);
+
+        assert_eq!(Outcome::TooLow, s.submit_at(&p, "100", 1000).unwrap());
+        let request = requests.lock().unwrap()[0].clone();
+        assert!(request.starts_with("POST /2022/day/1/answer HTTP/1.1"));
+        assert!(request.contains("Cookie: session=abc"));
+        assert!(request.ends_with("level=2&answer=100"));
+
+        assert!(matches!(
+            s.submit_at(&p, "200", 1030),
+            Err(SubmitError::Cooldown(_))
+        ));
+        // other years wait too
+        assert!(matches!(
+            s.submit_at(&Puzzle::new(2021, 1, 1), "200", 1030),
+            Err(SubmitError::Cooldown(_))
+        ));
+        assert!(matches!(
+            s.submit_at(&p, "100", 2000),
+            Err(SubmitError::KnownWrong(Outcome::TooLow))
+        ));
+        assert!(matches!(
+            s.submit_at(&p, "50", 2000),
+            Err(SubmitError::KnownWrong(Outcome::TooLow))
+        ));
+        assert_eq!(Outcome::Right, s.submit_at(&p, "200", 2000).unwrap());
+        assert!(matches!(
+            s.submit_at(&p, "300", 3000),
+            Err(SubmitError::AlreadySolved)
+        ));
+
+        let log = fs::read_to_string(&s.log_path).unwrap();
+        assert_eq!(
+            "1000\t2022/1/2\t100\ttoo-low\t60\n2000\t2022/1/2\t200\tright\t0\n",
+            log
+        );
+        assert_eq!(2, requests.lock().unwrap().len());
+        fs::remove_file(&s.log_path).unwrap();
+    }
+
+    #[test]
+    fn test_printed_only() {
+        let (addr, requests) = stub_server(vec!["That's the right answer!"]);
+        let s = submitter(&addr, "printed");
+        // the solver returns a placeholder 0, posting it would waste a try
+        assert!(matches!(
+            s.submit_at(&Puzzle::new(2022, 10, 2), "0", 1000),
+            Err(SubmitError::Config(_))
+        ));
+        assert!(requests.lock().unwrap().is_empty());
+        assert!(!s.log_path.exists());
+    }
+}
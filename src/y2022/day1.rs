@@ -0,0 +1,188 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+
+use crate::lint::Grammar;
+
+pub const VERSION: &str = "1";
+
+pub const GRAMMAR: Grammar = Grammar {
+    lines: &["{uint}", ""],
+    check: None,
+};
+
+/// the calories of each elf, in the order they appear in the input
+///
+/// yields `(elf_index, total)`, `elf_index` counting from 0. an elf ends at
+/// a blank line, runs of blank lines don't make empty elves
+pub struct Blocks<R> {
+    lines: io::Lines<R>,
+    line_no: usize,
+    elf: usize,
+}
+
+pub fn blocks<R: BufRead>(reader: R) -> Blocks<R> {
+    Blocks {
+        lines: reader.lines(),
+        line_no: 0,
+        elf: 0,
+    }
+}
+
+impl<R: BufRead> Iterator for Blocks<R> {
+    type Item = io::Result<(usize, u64)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut total: Option<u64> = None;
+        loop {
+            let line = match self.lines.next() {
+                Some(Ok(line)) => line,
+                Some(Err(e)) => return Some(Err(e)),
+                None => break,
+            };
+            self.line_no += 1;
+            let line = line.trim();
+            if line.is_empty() {
+                if total.is_some() {
+                    break;
+                }
+                continue;
+            }
+            let calories: u64 = match line.parse() {
+                Ok(x) => x,
+                Err(_) => {
+                    return Some(Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("line {}: expected calories, got {:?}", self.line_no, line),
+                    )))
+                }
+            };
+            total = Some(total.unwrap_or(0) + calories);
+        }
+
+        let total = total?;
+        self.elf += 1;
+        Some(Ok((self.elf - 1, total)))
+    }
+}
+
+/// the `n` elves carrying the most, most first. ties go to the earlier elf
+pub fn top(totals: &[(usize, u64)], n: usize) -> Vec<(usize, u64)> {
+    let mut sorted = totals.to_vec();
+    sorted.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    sorted.truncate(n);
+    sorted
+}
+
+pub fn median(totals: &[(usize, u64)]) -> Option<f64> {
+    let mut values: Vec<u64> = totals.iter().map(|(_, t)| *t).collect();
+    values.sort_unstable();
+    let mid = values.len() / 2;
+    match values.len() {
+        0 => None,
+        len if len % 2 == 1 => Some(values[mid] as f64),
+        _ => Some((values[mid - 1] as f64 + values[mid] as f64) / 2.0),
+    }
+}
+
+pub fn mean(totals: &[(usize, u64)]) -> Option<f64> {
+    if totals.is_empty() {
+        return None;
+    }
+    let sum: u64 = totals.iter().map(|(_, t)| *t).sum();
+    Some(sum as f64 / totals.len() as f64)
+}
+
+/// the `n` winning elves by position (1-based, as in the puzzle text), plus
+/// the totals across everyone
+pub fn report(totals: &[(usize, u64)], n: usize) -> String {
+    let winners = top(totals, n);
+    let mut out = String::new();
+    for (rank, (elf, total)) in winners.iter().enumerate() {
+        out.push_str(&format!(
+            "#{} elf {} carries {}\n",
+            rank + 1,
+            elf + 1,
+            total
+        ));
+    }
+    out.push_str(&format!(
+        "top {} carry {}\n",
+        winners.len(),
+        winners.iter().map(|(_, t)| t).sum::<u64>()
+    ));
+    if let (Some(median), Some(mean)) = (median(totals), mean(totals)) {
+        out.push_str(&format!(
+            "{} elves, median {:.1}, mean {:.1}\n",
+            totals.len(),
+            median,
+            mean
+        ));
+    }
+    out
+}
+
+pub fn totals(filepath: &str) -> Vec<(usize, u64)> {
+    let file = File::open(filepath).expect("expect file");
+    blocks(BufReader::new(file))
+        .collect::<io::Result<_>>()
+        .expect("expect calories")
+}
+
+fn sum_top(filepath: &str, n: usize) -> u64 {
+    top(&totals(filepath), n).iter().map(|(_, t)| t).sum()
+}
+
+pub fn part1(filepath: &str) -> u64 {
+    sum_top(filepath, 1)
+}
+
+pub fn part2(filepath: &str) -> u64 {
+    sum_top(filepath, 3)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(24000, part1("data/2022/day1-sample.txt"));
+        assert_eq!(75622, part1("data/2022/day1.txt"));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(45000, part2("data/2022/day1-sample.txt"));
+        assert_eq!(213159, part2("data/2022/day1.txt"));
+    }
+
+    #[test]
+    fn test_blocks() {
+        let input = "1000\n2000\n\n\n4000\n\n5000\n6000";
+        let totals: Vec<_> = blocks(input.as_bytes()).map(|b| b.unwrap()).collect();
+        assert_eq!(vec![(0, 3000), (1, 4000), (2, 11000)], totals);
+
+        // would overflow a u32
+        let input = "4294967295\n1\n";
+        let totals: Vec<_> = blocks(input.as_bytes()).map(|b| b.unwrap()).collect();
+        assert_eq!(vec![(0, 4294967296)], totals);
+
+        let err = blocks("1\n\nx\n".as_bytes()).nth(1).unwrap().unwrap_err();
+        assert_eq!("line 3: expected calories, got \"x\"", err.to_string());
+    }
+
+    #[test]
+    fn test_stats() {
+        let totals = totals("data/2022/day1-sample.txt");
+        assert_eq!(vec![(3, 24000), (2, 11000), (4, 10000)], top(&totals, 3));
+        assert_eq!(Some(10000.0), median(&totals));
+        assert_eq!(Some(11000.0), mean(&totals));
+        assert_eq!(Some(2.5), median(&[(0, 2), (1, 3)]));
+        assert_eq!(None, median(&[]));
+        assert_eq!(None, mean(&[]));
+        assert_eq!(
+            "#1 elf 4 carries 24000\n#2 elf 3 carries 11000\ntop 2 carry 35000\n5 elves, median 10000.0, mean 11000.0\n",
+            report(&totals, 2)
+        );
+    }
+}
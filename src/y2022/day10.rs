@@ -1,6 +1,15 @@
 use std::fs;
 use std::str;
 
+use crate::lint::Grammar;
+
+pub const VERSION: &str = "1";
+
+pub const GRAMMAR: Grammar = Grammar {
+    lines: &["noop", "addx {int}"],
+    check: None,
+};
+
 pub fn part1(filepath: &str) -> i32 {
     let cnt = fs::read_to_string(filepath).expect("expect file");
 
@@ -121,13 +130,13 @@ mod tests {
 
     #[test]
     fn test_part1() {
-        assert_eq!(13140, part1("data/day10-sample.txt"));
-        assert_eq!(13720, part1("data/day10.txt"));
+        assert_eq!(13140, part1("data/2022/day10-sample.txt"));
+        assert_eq!(13720, part1("data/2022/day10.txt"));
     }
 
     #[test]
     fn test_part2_sample() {
-        let cnt = fs::read_to_string("data/day10-sample.txt").expect("expect file");
+        let cnt = fs::read_to_string("data/2022/day10-sample.txt").expect("expect file");
         let mut grid = Vec::new();
         render_part2(cnt.lines(), &mut grid);
         assert_eq!(
@@ -145,7 +154,7 @@ mod tests {
 
     #[test]
     fn test_part2() {
-        let cnt = fs::read_to_string("data/day10.txt").expect("expect file");
+        let cnt = fs::read_to_string("data/2022/day10.txt").expect("expect file");
         let mut grid = Vec::new();
         render_part2(cnt.lines(), &mut grid);
         assert_eq!(
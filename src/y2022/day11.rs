@@ -3,6 +3,25 @@ use std::fs;
 use std::iter::Peekable;
 use std::str;
 
+use crate::lint::Grammar;
+
+pub const VERSION: &str = "1";
+
+pub const GRAMMAR: Grammar = Grammar {
+    lines: &[
+        "Monkey {uint}:",
+        "  Starting items:",
+        "  Starting items: {uint}(, {uint})*",
+        "  Operation: new = old [*+] {uint}",
+        "  Operation: new = old [*+] old",
+        "  Test: divisible by {uint}",
+        "    If true: throw to monkey {uint}",
+        "    If false: throw to monkey {uint}",
+        "",
+    ],
+    check: None,
+};
+
 #[derive(Debug)]
 enum MonkeyOperator {
     Multiply,
@@ -332,14 +351,14 @@ mod tests {
 
     #[test]
     fn test_part1() {
-        assert_eq!(10605, part1("data/day11-sample.txt"));
-        assert_eq!(112221, part1("data/day11.txt"));
+        assert_eq!(10605, part1("data/2022/day11-sample.txt"));
+        assert_eq!(112221, part1("data/2022/day11.txt"));
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(2713310158, part2("data/day11-sample.txt"));
-        assert_eq!(25272176808, part2("data/day11.txt"));
+        assert_eq!(2713310158, part2("data/2022/day11-sample.txt"));
+        assert_eq!(25272176808, part2("data/2022/day11.txt"));
     }
 
     #[test]
@@ -2,6 +2,81 @@ use std::collections::HashSet;
 use std::fs;
 use std::str;
 
+use crate::lint::{Grammar, Issue};
+
+pub const VERSION: &str = "1";
+
+pub const GRAMMAR: Grammar = Grammar {
+    lines: &["[a-zSE]+"],
+    check: Some(check_input),
+};
+
+// find_path only stops once it reaches E, so make sure it can
+fn check_input(input: &str) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    let grid: Vec<&[u8]> = input.lines().map(|l| l.as_bytes()).collect();
+    let width = grid.first().map_or(0, |r| r.len());
+
+    let mut starts = Vec::new();
+    let mut stops = Vec::new();
+    for (y, row) in grid.iter().enumerate() {
+        if row.len() != width {
+            issues.push(Issue::at(
+                y + 1,
+                format!("{} squares, expected {}", row.len(), width),
+            ));
+        }
+        for (x, b) in row.iter().enumerate() {
+            match b {
+                b'S' => starts.push((x, y)),
+                b'E' => stops.push((x, y)),
+                _ => {}
+            }
+        }
+    }
+    for (name, found) in [("S", &starts), ("E", &stops)] {
+        if found.len() != 1 {
+            issues.push(Issue::file(format!(
+                "expected exactly one {}, found {}",
+                name,
+                found.len()
+            )));
+        }
+    }
+    if !issues.is_empty() {
+        return issues;
+    }
+
+    let height = |b: u8| match b {
+        b'S' => b'a',
+        b'E' => b'z',
+        b => b,
+    };
+    let mut seen = HashSet::new();
+    let mut frontiers = vec![starts[0]];
+    seen.insert(starts[0]);
+    while let Some((x, y)) = frontiers.pop() {
+        let v = height(grid[y][x]);
+        let neighbours = [
+            (x.wrapping_sub(1), y),
+            (x + 1, y),
+            (x, y.wrapping_sub(1)),
+            (x, y + 1),
+        ];
+        for (nx, ny) in neighbours {
+            if let Some(b) = grid.get(ny).and_then(|r| r.get(nx)) {
+                if height(*b) <= v + 1 && seen.insert((nx, ny)) {
+                    frontiers.push((nx, ny));
+                }
+            }
+        }
+    }
+    if !seen.contains(&stops[0]) {
+        issues.push(Issue::file("E can't be reached from S"));
+    }
+    issues
+}
+
 type Pos = (usize, usize);
 
 fn read_to_grid(filepath: &str) -> (Vec<Vec<u8>>, Pos, Pos, Vec<Pos>) {
@@ -114,13 +189,26 @@ mod tests {
 
     #[test]
     fn test_part1() {
-        assert_eq!(31, part1("data/day12-sample.txt"));
-        assert_eq!(456, part1("data/day12.txt"));
+        assert_eq!(31, part1("data/2022/day12-sample.txt"));
+        assert_eq!(456, part1("data/2022/day12.txt"));
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(29, part2("data/day12-sample.txt"));
-        assert_eq!(454, part2("data/day12.txt"));
+        assert_eq!(29, part2("data/2022/day12-sample.txt"));
+        assert_eq!(454, part2("data/2022/day12.txt"));
+    }
+
+    #[test]
+    fn test_check_input() {
+        assert!(check_input("SbcdefghijklmnopqrstuvwxyE\n").is_empty());
+        assert_eq!(
+            vec![Issue::file("E can't be reached from S")],
+            check_input("SbdE\n")
+        );
+        assert_eq!(
+            vec![Issue::file("expected exactly one E, found 0")],
+            check_input("Sbc\n")
+        );
     }
 }
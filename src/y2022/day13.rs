@@ -2,6 +2,55 @@ use std::cmp::Ordering;
 use std::fs;
 use std::str;
 
+use crate::lint::{Grammar, Issue};
+
+pub const VERSION: &str = "1";
+
+pub const GRAMMAR: Grammar = Grammar {
+    lines: &["[\\[\\],0-9]+", ""],
+    check: Some(check_input),
+};
+
+// packets come in pairs separated by a blank line
+fn check_input(input: &str) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    let mut pair_start = None;
+    let mut pair_len = 0;
+    for (idx, line) in input.lines().chain([""]).enumerate() {
+        if line.is_empty() {
+            if pair_len != 0 && pair_len != 2 {
+                issues.push(Issue::at(
+                    pair_start.unwrap(),
+                    format!("a pair of {} packets", pair_len),
+                ));
+            }
+            pair_len = 0;
+            continue;
+        }
+        if pair_len == 0 {
+            pair_start = Some(idx + 1);
+        }
+        pair_len += 1;
+
+        let mut depth: i32 = 0;
+        let mut balanced = line.starts_with('[');
+        for b in line.bytes() {
+            match b {
+                b'[' => depth += 1,
+                b']' => depth -= 1,
+                _ => {}
+            }
+            if depth < 0 {
+                balanced = false;
+            }
+        }
+        if !balanced || depth != 0 {
+            issues.push(Issue::at(idx + 1, "unbalanced brackets"));
+        }
+    }
+    issues
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 enum Node {
     Number(Box<u32>),
@@ -222,16 +271,16 @@ mod tests {
 
     #[test]
     fn test_part1() {
-        assert_eq!(13, part1("data/day13-sample.txt"));
-        assert_eq!(6428, part1("data/day13.txt"));
+        assert_eq!(13, part1("data/2022/day13-sample.txt"));
+        assert_eq!(6428, part1("data/2022/day13.txt"));
     }
 
     #[test]
     fn test_part2() {
-        let buf = read_to_sorted_packets("data/day13-sample.txt");
+        let buf = read_to_sorted_packets("data/2022/day13-sample.txt");
         let divider_idx = find_divider(&buf);
         assert_eq!([10, 14], &divider_idx[..]);
-        let buf = read_to_sorted_packets("data/day13.txt");
+        let buf = read_to_sorted_packets("data/2022/day13.txt");
         let divider_idx = find_divider(&buf);
         assert_eq!([117, 192], &divider_idx[..]);
     }
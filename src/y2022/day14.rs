@@ -1,6 +1,15 @@
 use std::collections::HashSet;
 use std::{fs, str};
 
+use crate::lint::Grammar;
+
+pub const VERSION: &str = "1";
+
+pub const GRAMMAR: Grammar = Grammar {
+    lines: &["{uint},{uint}( -> {uint},{uint})*"],
+    check: None,
+};
+
 type Point = (u32, u32);
 
 fn parse_line(line: &str) -> Vec<Point> {
@@ -284,29 +293,29 @@ mod tests {
 
     #[test]
     fn test_part1() {
-        assert_eq!(24, part1("data/day14-sample.txt"));
-        assert_eq!(825, part1("data/day14.txt"));
+        assert_eq!(24, part1("data/2022/day14-sample.txt"));
+        assert_eq!(825, part1("data/2022/day14.txt"));
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(93, part2("data/day14-sample.txt"));
-        assert_eq!(26729, part2("data/day14.txt"));
+        assert_eq!(93, part2("data/2022/day14-sample.txt"));
+        assert_eq!(26729, part2("data/2022/day14.txt"));
     }
 
     #[test]
     fn test_part2_v1() {
-        assert_eq!(93, part2_v1("data/day14-sample.txt"));
-        assert_eq!(26729, part2_v1("data/day14.txt"));
+        assert_eq!(93, part2_v1("data/2022/day14-sample.txt"));
+        assert_eq!(26729, part2_v1("data/2022/day14.txt"));
     }
 
     #[bench]
     fn bench_part2(b: &mut Bencher) {
-        b.iter(|| part2("data/day14.txt"));
+        b.iter(|| part2("data/2022/day14.txt"));
     }
 
     #[bench]
     fn bench_part2_v1(b: &mut Bencher) {
-        b.iter(|| part2_v1("data/day14.txt"));
+        b.iter(|| part2_v1("data/2022/day14.txt"));
     }
 }
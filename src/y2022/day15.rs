@@ -1,8 +1,17 @@
 use std::collections::{HashMap, HashSet};
 use std::{fs, str};
 
+use crate::interval::{self, Interval};
+use crate::lint::Grammar;
+
+pub const VERSION: &str = "1";
+
+pub const GRAMMAR: Grammar = Grammar {
+    lines: &["Sensor at x={int}, y={int}: closest beacon is at x={int}, y={int}"],
+    check: None,
+};
+
 type Point = (i32, i32);
-type Range = (i32, i32);
 
 // this will consume an extra byte (not a digit)
 // it's fine in our case tho ¯\_(ツ)_/¯
@@ -48,21 +57,25 @@ fn parse_file(filepath: &str) -> (Vec<Point>, HashMap<Point, Point>) {
     (sensors, sensor_beacon)
 }
 
-fn calc_sensor_cover_range_on_row(sensor: &Point, beacon: &Point, row: i32) -> Option<Range> {
+fn calc_sensor_cover_range_on_row(
+    sensor: &Point,
+    beacon: &Point,
+    row: i32,
+) -> Option<Interval<i32>> {
     let dis = manhattan_distance(&sensor, &beacon);
     let y = (sensor.1 - row).abs();
     if y > dis {
         return None;
     }
     let diff = dis - y;
-    Some((sensor.0 - diff, sensor.0 + diff))
+    Some(Interval::new(sensor.0 - diff, sensor.0 + diff))
 }
 
 fn calc_beacon_free_range_on_row(
     sensors: &Vec<Point>,
     sensor_beacon: &HashMap<Point, Point>,
     row: i32,
-) -> Vec<Range> {
+) -> Vec<Interval<i32>> {
     sensors
         .iter()
         .map(|s| calc_sensor_cover_range_on_row(&s, &sensor_beacon.get(&s).unwrap(), row))
@@ -74,14 +87,14 @@ fn calc_beacon_free_range_on_row(
 pub fn part1(filepath: &str, row: i32) -> u32 {
     let (sensors, sensor_beacon) = parse_file(filepath);
 
-    let mut ranges = calc_beacon_free_range_on_row(&sensors, &sensor_beacon, row);
-    ranges.sort_by(|a, b| a.0.cmp(&b.0));
+    let ranges = interval::merge(calc_beacon_free_range_on_row(&sensors, &sensor_beacon, row));
 
     let mut seen = HashSet::new();
 
     let beacon_on_row = sensors.iter().fold(0, |acc, x| {
         let b = sensor_beacon.get(&x).unwrap();
-        if b.1 == row {
+        // only beacons on one of the counted positions take one away
+        if b.1 == row && ranges.iter().any(|r| r.contains(b.0)) {
             if seen.contains(&b) {
                 acc
             } else {
@@ -93,19 +106,7 @@ pub fn part1(filepath: &str, row: i32) -> u32 {
         }
     });
 
-    let mut prev_max = ranges[0].1;
-    let mut count = ranges[0].1 - ranges[0].0 + 1;
-    let mut iter = ranges.iter();
-    iter.next();
-    for r in iter {
-        if r.0 > prev_max {
-            count += r.1 - r.0 + 1;
-            prev_max = r.1;
-        } else if r.1 > prev_max {
-            count += r.1 - prev_max;
-            prev_max = r.1;
-        }
-    }
+    let count: u64 = ranges.iter().map(|r| r.len()).sum();
     count as u32 - beacon_on_row
 }
 
@@ -141,33 +142,18 @@ fn find_bound_of_sensor(sensor: &Point, beacon: &Point) -> (Point, Point, Point,
 
 pub fn part2(filepath: &str, bound: i32) -> Point {
     let (sensors, sensor_beacon) = parse_file(filepath);
+    let window = Interval::new(0, bound);
     // this is bruteforce...
     for y in 0..=bound {
-        let mut ranges = calc_beacon_free_range_on_row(&sensors, &sensor_beacon, y);
-        ranges.sort_by(|a, b| a.0.cmp(&b.0));
-
-        // prev to represent a x-axis range (inclued both .0 and .1) where another beacon can still
-        // be placed
-        let mut prev = (0, bound);
-        for r in ranges {
-            if r.1 < 0 {
-                continue;
-            }
-            if r.0 > bound {
-                break;
-            }
-            if r.0 > prev.0 {
-                let x = prev.0;
-                return (x, y);
-                // ret = 1;
-                // break;
-            }
-            if r.1 >= prev.0 {
-                prev.0 = r.1 + 1;
-                if prev.0 >= bound {
-                    break;
-                }
-            }
+        let ranges = calc_beacon_free_range_on_row(&sensors, &sensor_beacon, y);
+        let covered = interval::merge(ranges.iter().filter_map(|r| r.intersection(&window)));
+
+        // the first x-axis position no sensor covers, if any
+        match covered.first() {
+            None => return (0, y),
+            Some(first) if first.start > 0 => return (0, y),
+            Some(first) if first.end < bound => return (first.end + 1, y),
+            _ => {}
         }
     }
     (0, 0)
@@ -179,14 +165,14 @@ mod tests {
 
     #[test]
     fn test_part1() {
-        assert_eq!(26, part1("data/day15-sample.txt", 10));
-        assert_eq!(5108096, part1("data/day15.txt", 2000000));
+        assert_eq!(26, part1("data/2022/day15-sample.txt", 10));
+        assert_eq!(5108096, part1("data/2022/day15.txt", 2000000));
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!((14, 11), part2("data/day15-sample.txt", 20));
+        assert_eq!((14, 11), part2("data/2022/day15-sample.txt", 20));
         // warning: this is slow
-        // assert_eq!((2638485, 2650264), part2("data/day15.txt", 4000000));
+        // assert_eq!((2638485, 2650264), part2("data/2022/day15.txt", 4000000));
     }
 }
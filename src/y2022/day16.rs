@@ -6,6 +6,15 @@ use std::{
     str,
 };
 
+use crate::lint::Grammar;
+
+pub const VERSION: &str = "1";
+
+pub const GRAMMAR: Grammar = Grammar {
+    lines: &["Valve [A-Z]+ has flow rate={uint}; tunnels? leads? to valves? [A-Z]+(, [A-Z]+)*"],
+    check: None,
+};
+
 #[derive(Debug)]
 struct Valve {
     name: String,
@@ -488,14 +497,14 @@ mod tests {
 
     #[test]
     fn test_part1() {
-        assert_eq!(1651, part1("data/day16-sample.txt"));
-        assert_eq!(2056, part1("data/day16.txt"));
+        assert_eq!(1651, part1("data/2022/day16-sample.txt"));
+        assert_eq!(2056, part1("data/2022/day16.txt"));
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(1707, part2("data/day16-sample.txt"));
+        assert_eq!(1707, part2("data/2022/day16-sample.txt"));
         // warnning - this takes 2 minutes to finish
-        // assert_eq!(2513, part2("data/day16.txt"));
+        // assert_eq!(2513, part2("data/2022/day16.txt"));
     }
 }
@@ -1,5 +1,14 @@
 use std::{collections::HashMap, fs, str};
 
+use crate::lint::Grammar;
+
+pub const VERSION: &str = "1";
+
+pub const GRAMMAR: Grammar = Grammar {
+    lines: &["[<>]+", ""],
+    check: None,
+};
+
 #[derive(Clone)]
 enum Direction {
     Left,
@@ -229,7 +238,7 @@ fn process(filepath: &str, rock_generation: u64) -> u64 {
     let (rocks_vec, dirs_vec) = parse_file(filepath);
     let mut rocks = rocks_vec.into_iter().cycle();
     let mut dirs_iter = dirs_vec.iter().cycle();
-    calc2(&mut dirs_iter, &mut rocks, rock_generation  + 1)
+    calc2(&mut dirs_iter, &mut rocks, rock_generation + 1)
 }
 
 pub fn part1(filepath: &str) -> u64 {
@@ -286,13 +295,13 @@ mod tests {
 
     #[test]
     fn test_part1() {
-        assert_eq!(3068, part1("data/day17-sample.txt"));
-        assert_eq!(3119, part1("data/day17.txt"));
+        assert_eq!(3068, part1("data/2022/day17-sample.txt"));
+        assert_eq!(3119, part1("data/2022/day17.txt"));
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(1514285714288, part2("data/day17-sample.txt"));
-        assert_eq!(1536994219669, part2("data/day17.txt"));
+        assert_eq!(1514285714288, part2("data/2022/day17-sample.txt"));
+        assert_eq!(1536994219669, part2("data/2022/day17.txt"));
     }
 }
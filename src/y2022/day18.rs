@@ -3,6 +3,15 @@ use std::{
     fs, str,
 };
 
+use crate::lint::Grammar;
+
+pub const VERSION: &str = "1";
+
+pub const GRAMMAR: Grammar = Grammar {
+    lines: &["{uint},{uint},{uint}"],
+    check: None,
+};
+
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 struct Position {
     x: u32,
@@ -277,13 +286,13 @@ mod tests {
 
     #[test]
     fn test_part1() {
-        assert_eq!(64, part1("data/day18-sample.txt"));
-        assert_eq!(3390, part1("data/day18.txt"));
+        assert_eq!(64, part1("data/2022/day18-sample.txt"));
+        assert_eq!(3390, part1("data/2022/day18.txt"));
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(58, part2("data/day18-sample.txt"));
-        assert_eq!(2058, part2("data/day18.txt"));
+        assert_eq!(58, part2("data/2022/day18-sample.txt"));
+        assert_eq!(2058, part2("data/2022/day18.txt"));
     }
 }
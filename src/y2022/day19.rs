@@ -1,5 +1,16 @@
 use std::{collections::VecDeque, fs, iter::Peekable, str};
 
+use crate::lint::Grammar;
+
+pub const VERSION: &str = "1";
+
+pub const GRAMMAR: Grammar = Grammar {
+    lines: &[
+        "Blueprint {uint}: Each ore robot costs {uint} ore. Each clay robot costs {uint} ore. Each obsidian robot costs {uint} ore and {uint} clay. Each geode robot costs {uint} ore and {uint} obsidian.",
+    ],
+    check: None,
+};
+
 struct GeodeCount {
     max: u32,
 }
@@ -193,13 +204,13 @@ mod tests {
 
     #[test]
     fn test_part1() {
-        assert_eq!(33, part1("data/day19-sample.txt"));
-        assert_eq!(1565, part1("data/day19.txt"));
+        assert_eq!(33, part1("data/2022/day19-sample.txt"));
+        assert_eq!(1565, part1("data/2022/day19.txt"));
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(3472, part2("data/day19-sample.txt"));
-        assert_eq!(10672, part2("data/day19.txt"));
+        assert_eq!(3472, part2("data/2022/day19-sample.txt"));
+        assert_eq!(10672, part2("data/2022/day19.txt"));
     }
 }
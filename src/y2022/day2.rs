@@ -0,0 +1,419 @@
+use std::fmt;
+use std::fs;
+
+use crate::lint::Grammar;
+
+pub const VERSION: &str = "1";
+
+pub const GRAMMAR: Grammar = Grammar {
+    lines: &["[ABC] [XYZ]"],
+    check: None,
+};
+
+// an index into `Rules::names`, also worth its index + 1 points
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Shape(pub usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub fn score(self) -> u32 {
+        match self {
+            Outcome::Lose => 0,
+            Outcome::Draw => 3,
+            Outcome::Win => 6,
+        }
+    }
+}
+
+/// which shape beats which
+///
+/// the opponent plays `A`, `B`, ... and we play the last letters of the
+/// alphabet, e.g. `X`, `Y`, `Z` with three shapes
+#[derive(Debug, Clone)]
+pub struct Rules {
+    pub names: Vec<&'static str>,
+    // beats[a][b]: a beats b
+    beats: Vec<Vec<bool>>,
+}
+
+impl Rules {
+    /// `beats` lists (winner, loser) pairs, every two shapes have to be in
+    /// exactly one of them, and every shape has to both win and lose
+    pub fn new(names: &[&'static str], beats: &[(usize, usize)]) -> Result<Self, String> {
+        let n = names.len();
+        if n == 0 || n > 13 {
+            return Err(format!("expected 1 to 13 shapes, got {}", n));
+        }
+        let mut matrix = vec![vec![false; n]; n];
+        for &(a, b) in beats {
+            if a >= n || b >= n || a == b {
+                return Err(format!("{} can't beat {}", a, b));
+            }
+            matrix[a][b] = true;
+        }
+        for a in 0..n {
+            for b in a + 1..n {
+                if matrix[a][b] == matrix[b][a] {
+                    return Err(format!(
+                        "exactly one of {} and {} should win",
+                        names[a], names[b]
+                    ));
+                }
+            }
+        }
+        // otherwise `shape_for` has nothing to pick
+        for a in 0..n {
+            if !(0..n).any(|b| matrix[a][b]) {
+                return Err(format!("{} never wins", names[a]));
+            }
+            if !(0..n).any(|b| matrix[b][a]) {
+                return Err(format!("{} never loses", names[a]));
+            }
+        }
+        Ok(Rules {
+            names: names.to_vec(),
+            beats: matrix,
+        })
+    }
+
+    pub fn classic() -> Self {
+        Rules::new(&["Rock", "Paper", "Scissors"], &[(0, 2), (1, 0), (2, 1)]).unwrap()
+    }
+
+    pub fn lizard_spock() -> Self {
+        Rules::new(
+            &["Rock", "Paper", "Scissors", "Lizard", "Spock"],
+            &[
+                (0, 2),
+                (0, 3),
+                (1, 0),
+                (1, 4),
+                (2, 1),
+                (2, 3),
+                (3, 1),
+                (3, 4),
+                (4, 0),
+                (4, 2),
+            ],
+        )
+        .unwrap()
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn outcome(&self, theirs: Shape, mine: Shape) -> Outcome {
+        if theirs == mine {
+            Outcome::Draw
+        } else if self.beats[mine.0][theirs.0] {
+            Outcome::Win
+        } else {
+            Outcome::Lose
+        }
+    }
+
+    /// the shape to play against `theirs` for `outcome`. with more than
+    /// three shapes several can do, the first one in `names` is picked
+    pub fn shape_for(&self, theirs: Shape, outcome: Outcome) -> Shape {
+        (0..self.len())
+            .map(Shape)
+            .find(|mine| self.outcome(theirs, *mine) == outcome)
+            .expect("every shape should win and lose against another")
+    }
+
+    fn their_shape(&self, letter: u8) -> Option<Shape> {
+        let idx = letter.checked_sub(b'A')? as usize;
+        (idx < self.len()).then_some(Shape(idx))
+    }
+
+    // index of our letter, `X`, `Y`, `Z` being 0, 1, 2 with three shapes
+    fn my_column(&self, letter: u8) -> Option<usize> {
+        let first = b'Z' + 1 - self.len() as u8;
+        let idx = letter.checked_sub(first)? as usize;
+        (idx < self.len()).then_some(idx)
+    }
+}
+
+/// how the second column of the guide is read
+#[derive(Debug, Clone, Copy)]
+pub enum Guide<'a> {
+    // the shape for each of our letters, part 1
+    Shapes(&'a [Shape]),
+    // the outcome for each of our letters, part 2
+    Outcomes(&'a [Outcome]),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    pub theirs: Shape,
+    pub mine: Shape,
+    pub outcome: Outcome,
+}
+
+impl Round {
+    pub fn score(&self) -> u32 {
+        self.mine.0 as u32 + 1 + self.outcome.score()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct RoundError {
+    // 1-based
+    pub line: usize,
+    pub row: String,
+}
+
+impl fmt::Display for RoundError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: unknown round {:?}", self.line, self.row)
+    }
+}
+
+impl std::error::Error for RoundError {}
+
+/// one row of the guide, e.g. "A Y"
+pub fn play(rules: &Rules, guide: Guide, row: &str) -> Option<Round> {
+    let (theirs, mine) = match row.as_bytes() {
+        [theirs, b' ', mine] => (rules.their_shape(*theirs)?, rules.my_column(*mine)?),
+        _ => return None,
+    };
+    let (mine, outcome) = match guide {
+        Guide::Shapes(shapes) => {
+            let mine = *shapes.get(mine)?;
+            (mine, rules.outcome(theirs, mine))
+        }
+        Guide::Outcomes(outcomes) => {
+            let outcome = *outcomes.get(mine)?;
+            (rules.shape_for(theirs, outcome), outcome)
+        }
+    };
+    Some(Round {
+        theirs,
+        mine,
+        outcome,
+    })
+}
+
+pub fn rounds(rules: &Rules, guide: Guide, input: &str) -> Result<Vec<Round>, RoundError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, row)| {
+            play(rules, guide, row).ok_or_else(|| RoundError {
+                line: idx + 1,
+                row: row.to_owned(),
+            })
+        })
+        .collect()
+}
+
+fn total_score(guide: Guide, filepath: &str) -> u32 {
+    let contents = fs::read_to_string(filepath).expect("expect file");
+    match rounds(&Rules::classic(), guide, &contents) {
+        Ok(rounds) => rounds.iter().map(|r| r.score()).sum(),
+        Err(e) => panic!("{}", e),
+    }
+}
+
+pub fn part1(filepath: &str) -> u32 {
+    total_score(Guide::Shapes(&[Shape(0), Shape(1), Shape(2)]), filepath)
+}
+
+pub fn part2(filepath: &str) -> u32 {
+    use Outcome::*;
+    total_score(Guide::Outcomes(&[Lose, Draw, Win]), filepath)
+}
+
+// every order of three items
+fn permutations<T: Copy>([a, b, c]: [T; 3]) -> [[T; 3]; 6] {
+    [
+        [a, b, c],
+        [a, c, b],
+        [b, a, c],
+        [b, c, a],
+        [c, a, b],
+        [c, b, a],
+    ]
+}
+
+/// a way of reading X/Y/Z, and what the guide scores read that way
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scored<T> {
+    pub mapping: [T; 3],
+    pub score: u32,
+}
+
+fn score_all(guide: Guide, input: &str) -> Result<u32, RoundError> {
+    let rounds = rounds(&Rules::classic(), guide, input)?;
+    Ok(rounds.iter().map(|r| r.score()).sum())
+}
+
+/// the score of all 6 ways to map X/Y/Z to shapes
+pub fn analyse_shapes(input: &str) -> Result<Vec<Scored<Shape>>, RoundError> {
+    permutations([Shape(0), Shape(1), Shape(2)])
+        .into_iter()
+        .map(|mapping| {
+            let score = score_all(Guide::Shapes(&mapping), input)?;
+            Ok(Scored { mapping, score })
+        })
+        .collect()
+}
+
+/// the score of all 6 ways to map X/Y/Z to outcomes
+pub fn analyse_outcomes(input: &str) -> Result<Vec<Scored<Outcome>>, RoundError> {
+    use Outcome::*;
+    permutations([Lose, Draw, Win])
+        .into_iter()
+        .map(|mapping| {
+            let score = score_all(Guide::Outcomes(&mapping), input)?;
+            Ok(Scored { mapping, score })
+        })
+        .collect()
+}
+
+// one line per mapping, then the best, worst and expected score when the
+// mapping is picked at random. the first mapping is the puzzle's reading
+fn report_mappings<T>(out: &mut String, scored: &[Scored<T>], name: impl Fn(&T) -> String) {
+    let names = |s: &Scored<T>| s.mapping.iter().map(&name).collect::<Vec<_>>().join("/");
+    for (idx, s) in scored.iter().enumerate() {
+        let note = if idx == 0 { " (puzzle)" } else { "" };
+        out.push_str(&format!("  {:<24} {}{}\n", names(s), s.score, note));
+    }
+    let best = scored.iter().max_by_key(|s| s.score).unwrap();
+    let worst = scored.iter().min_by_key(|s| s.score).unwrap();
+    let expected = scored.iter().map(|s| s.score as f64).sum::<f64>() / scored.len() as f64;
+    out.push_str(&format!(
+        "  best {} ({}), worst {} ({}), expected {:.1}\n",
+        best.score,
+        names(best),
+        worst.score,
+        names(worst),
+        expected
+    ));
+}
+
+pub fn report(filepath: &str) -> String {
+    let contents = fs::read_to_string(filepath).expect("expect file");
+    let rules = Rules::classic();
+    let mut out = String::new();
+    match analyse_shapes(&contents) {
+        Ok(scored) => {
+            out.push_str("X/Y/Z as shapes:\n");
+            report_mappings(&mut out, &scored, |s| rules.names[s.0].to_owned());
+        }
+        Err(e) => return format!("{}\n", e),
+    }
+    if let Ok(scored) = analyse_outcomes(&contents) {
+        out.push_str("X/Y/Z as outcomes:\n");
+        report_mappings(&mut out, &scored, |o| format!("{:?}", o));
+    }
+    // with five shapes our letters are V to Z, so X/Y/Z are the last three
+    let rules = Rules::lizard_spock();
+    let shapes: Vec<Shape> = (0..rules.len()).map(Shape).collect();
+    if let Ok(rounds) = rounds(&rules, Guide::Shapes(&shapes), &contents) {
+        let score: u32 = rounds.iter().map(|r| r.score()).sum();
+        out.push_str(&format!(
+            "X/Y/Z as {}, playing {}: {}\n",
+            rules.names[2..].join("/"),
+            rules.names.join(" "),
+            score
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        assert_eq!(11449, part1("data/2022/day2.txt"));
+    }
+
+    #[test]
+    fn test_part_two() {
+        assert_eq!(13187, part2("data/2022/day2.txt"));
+    }
+
+    #[test]
+    fn test_rounds() {
+        let rules = Rules::classic();
+        let guide = Guide::Shapes(&[Shape(0), Shape(1), Shape(2)]);
+        let rounds = rounds(&rules, guide, "A Y\nB X\nC Z\n").unwrap();
+        let outcomes: Vec<_> = rounds.iter().map(|r| (r.outcome, r.score())).collect();
+        use Outcome::*;
+        assert_eq!(vec![(Win, 8), (Lose, 1), (Draw, 6)], outcomes);
+
+        let guide = Guide::Outcomes(&[Lose, Draw, Win]);
+        assert_eq!(
+            Some(Round {
+                theirs: Shape(0),
+                mine: Shape(0),
+                outcome: Draw
+            }),
+            play(&rules, guide, "A Y")
+        );
+        assert_eq!(
+            Err(RoundError {
+                line: 2,
+                row: "D X".to_owned()
+            }),
+            super::rounds(&rules, guide, "A Y\nD X\n")
+        );
+    }
+
+    #[test]
+    fn test_analyse() {
+        let shapes = analyse_shapes("A Y\nB X\nC Z\n").unwrap();
+        assert_eq!(6, shapes.len());
+        assert_eq!(15, shapes[0].score);
+        let best = shapes.iter().max_by_key(|s| s.score).unwrap();
+        assert_eq!(24, best.score);
+        assert_eq!([Shape(2), Shape(1), Shape(0)], best.mapping);
+
+        let outcomes = analyse_outcomes("A Y\nB X\nC Z\n").unwrap();
+        assert_eq!(12, outcomes[0].score);
+        // the puzzle's reading scores the same as the parts
+        let contents = fs::read_to_string("data/2022/day2.txt").unwrap();
+        assert_eq!(11449, analyse_shapes(&contents).unwrap()[0].score);
+        assert_eq!(13187, analyse_outcomes(&contents).unwrap()[0].score);
+
+        assert!(analyse_shapes("A Q\n").is_err());
+    }
+
+    #[test]
+    fn test_lizard_spock() {
+        let rules = Rules::lizard_spock();
+        let (rock, spock, lizard) = (Shape(0), Shape(4), Shape(3));
+        assert_eq!(Outcome::Win, rules.outcome(rock, spock));
+        assert_eq!(Outcome::Lose, rules.outcome(rock, lizard));
+        assert_eq!(Outcome::Win, rules.outcome(spock, lizard));
+        assert_eq!(Shape(1), rules.shape_for(rock, Outcome::Win));
+
+        // five shapes are played with V to Z
+        let shapes: Vec<_> = (0..5).map(Shape).collect();
+        let round = play(&rules, Guide::Shapes(&shapes), "A V").unwrap();
+        assert_eq!(
+            (Shape(0), Outcome::Draw, 4),
+            (round.mine, round.outcome, round.score())
+        );
+        assert_eq!(None, play(&rules, Guide::Shapes(&shapes), "F V"));
+
+        assert!(Rules::new(&["Rock", "Paper"], &[(0, 1), (1, 0)]).is_err());
+        assert!(Rules::new(&["Rock", "Paper"], &[]).is_err());
+        // one winner per pair, but Rock never loses and Scissors never wins
+        assert_eq!(
+            Err("Rock never loses".to_owned()),
+            Rules::new(&["Rock", "Paper", "Scissors"], &[(0, 1), (0, 2), (1, 2)]).map(|_| ())
+        );
+        assert!(Rules::new(&["Rock"], &[]).is_err());
+    }
+}
@@ -4,6 +4,15 @@ use std::fs;
 use std::rc::Rc;
 use std::str;
 
+use crate::lint::Grammar;
+
+pub const VERSION: &str = "1";
+
+pub const GRAMMAR: Grammar = Grammar {
+    lines: &["{int}"],
+    check: None,
+};
+
 type Link<T> = Option<Rc<RefCell<Node<T>>>>;
 
 #[derive(Debug)]
@@ -154,13 +163,13 @@ mod tests {
 
     #[test]
     fn test_part1() {
-        assert_eq!(3, solve(1, "data/day20-sample.txt"));
-        assert_eq!(8372, solve(1, "data/day20.txt"));
+        assert_eq!(3, solve(1, "data/2022/day20-sample.txt"));
+        assert_eq!(8372, solve(1, "data/2022/day20.txt"));
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(1623178306, solve(2, "data/day20-sample.txt"));
-        assert_eq!(7865110481723, solve(2, "data/day20.txt"));
+        assert_eq!(1623178306, solve(2, "data/2022/day20-sample.txt"));
+        assert_eq!(7865110481723, solve(2, "data/2022/day20.txt"));
     }
 }
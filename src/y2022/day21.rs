@@ -3,6 +3,15 @@ use std::fs;
 use std::iter::Peekable;
 use std::str;
 
+use crate::lint::Grammar;
+
+pub const VERSION: &str = "1";
+
+pub const GRAMMAR: Grammar = Grammar {
+    lines: &["[a-z]+: {uint}", "[a-z]+: [a-z]+ [+\\-*/] [a-z]+"],
+    check: None,
+};
+
 #[derive(Debug, Clone)]
 enum MathOp {
     Add,
@@ -219,7 +228,10 @@ mod tests {
 
     #[test]
     fn test_solve() {
-        assert_eq!((152, 301), solve("data/day21-sample.txt"));
-        assert_eq!((324122188240430, 3412650897405), solve("data/day21.txt"));
+        assert_eq!((152, 301), solve("data/2022/day21-sample.txt"));
+        assert_eq!(
+            (324122188240430, 3412650897405),
+            solve("data/2022/day21.txt")
+        );
     }
 }
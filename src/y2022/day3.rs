@@ -0,0 +1,168 @@
+use std::fs;
+
+use crate::lint::{Grammar, Issue};
+
+pub const VERSION: &str = "2";
+
+pub const GRAMMAR: Grammar = Grammar {
+    lines: &["[a-zA-Z]+"],
+    check: Some(check_input),
+};
+
+// both compartments need the same size, and part 2 works on groups of three
+fn check_input(input: &str) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    let mut count = 0;
+    for (idx, line) in input.lines().enumerate() {
+        if line.len() % 2 != 0 {
+            issues.push(Issue::at(idx + 1, "odd number of items"));
+        }
+        count += 1;
+    }
+    if count % 3 != 0 {
+        issues.push(Issue::file(format!(
+            "{} rucksacks can't be split into groups of three",
+            count
+        )));
+    }
+    issues
+}
+
+/// a set of items, bit `priority` set for every item in it
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Items(u64);
+
+// a-z are 1 to 26, A-Z 27 to 52
+pub fn priority(item: u8) -> Option<u32> {
+    match item {
+        b'a'..=b'z' => Some(1 + (item - b'a') as u32),
+        b'A'..=b'Z' => Some(27 + (item - b'A') as u32),
+        _ => None,
+    }
+}
+
+fn item(priority: u32) -> u8 {
+    if priority <= 26 {
+        b'a' + (priority - 1) as u8
+    } else {
+        b'A' + (priority - 27) as u8
+    }
+}
+
+impl Items {
+    // anything that isn't a letter is left out
+    pub fn new(items: &[u8]) -> Self {
+        Items(
+            items
+                .iter()
+                .filter_map(|b| priority(*b))
+                .fold(0, |set, p| set | 1 << p),
+        )
+    }
+
+    pub fn all() -> Self {
+        // bits 1 to 52
+        Items(((1 << 53) - 1) & !1)
+    }
+
+    pub fn intersect(self, other: Items) -> Items {
+        Items(self.0 & other.0)
+    }
+
+    /// the items, lowest priority first
+    pub fn chars(self) -> impl Iterator<Item = char> {
+        (1..=52)
+            .filter(move |p| self.0 & (1 << p) != 0)
+            .map(|p| item(p) as char)
+    }
+
+    pub fn priority(self) -> u32 {
+        (1..=52).filter(|p| self.0 & (1 << p) != 0).sum()
+    }
+}
+
+/// what both compartments of each rucksack have in common
+pub fn by_compartment(input: &str) -> Vec<Items> {
+    input
+        .lines()
+        .map(|line| {
+            let (left, right) = line.as_bytes().split_at(line.len() / 2);
+            Items::new(left).intersect(Items::new(right))
+        })
+        .collect()
+}
+
+/// what each group of `size` rucksacks has in common, a trailing group
+/// that's too small is left out. `size` has to be at least 1
+pub fn by_group(input: &str, size: usize) -> Vec<Items> {
+    assert!(size > 0, "group size should be at least 1");
+    let lines: Vec<&str> = input.lines().collect();
+    lines
+        .chunks_exact(size)
+        .map(|group| {
+            group.iter().fold(Items::all(), |common, line| {
+                common.intersect(Items::new(line.as_bytes()))
+            })
+        })
+        .collect()
+}
+
+/// the common items one after the other, and their priority sum
+pub fn summary(common: &[Items]) -> (String, u32) {
+    let items = common.iter().flat_map(|c| c.chars()).collect();
+    let sum = common.iter().map(|c| c.priority()).sum();
+    (items, sum)
+}
+
+pub fn part1(filepath: &str) -> u32 {
+    let contents = fs::read_to_string(filepath).expect("expect file");
+    summary(&by_compartment(&contents)).1
+}
+
+pub fn part2(filepath: &str) -> u32 {
+    let contents = fs::read_to_string(filepath).expect("expect file");
+    summary(&by_group(&contents, 3)).1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        assert_eq!(8123, part1("data/2022/day3.txt"));
+    }
+
+    #[test]
+    fn test_part_two() {
+        assert_eq!(2620, part2("data/2022/day3.txt"));
+    }
+
+    #[test]
+    fn test_priority() {
+        assert_eq!(Some(1), priority(b'a'));
+        assert_eq!(Some(26), priority(b'z'));
+        assert_eq!(Some(27), priority(b'A'));
+        assert_eq!(Some(52), priority(b'Z'));
+        assert_eq!(None, priority(b'1'));
+        assert_eq!("aAZ", Items::new(b"ZAaa").chars().collect::<String>());
+    }
+
+    #[test]
+    fn test_sample() {
+        let sample = fs::read_to_string("data/2022/day3-sample.txt").unwrap();
+        assert_eq!(
+            ("pLPvts".to_owned(), 157),
+            summary(&by_compartment(&sample))
+        );
+        assert_eq!(("rZ".to_owned(), 70), summary(&by_group(&sample, 3)));
+        assert_eq!(("".to_owned(), 0), summary(&by_group(&sample, 6)));
+        assert_eq!(("A".to_owned(), 27), summary(&by_group("aA\nbA\n", 2)));
+    }
+
+    #[test]
+    #[should_panic(expected = "at least 1")]
+    fn test_empty_groups() {
+        by_group("aA\n", 0);
+    }
+}
@@ -0,0 +1,301 @@
+use std::fs;
+
+use crate::interval::Interval;
+use crate::lint::{Grammar, Issue};
+
+pub const VERSION: &str = "1";
+
+pub const GRAMMAR: Grammar = Grammar {
+    lines: &["{uint}-{uint},{uint}-{uint}"],
+    check: Some(check_input),
+};
+
+// the solver reads "5-3" as 3-5, but it's most likely a typo
+fn check_input(input: &str) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    for (idx, line) in input.lines().enumerate() {
+        for range in line.split(',') {
+            let bounds: Vec<u32> = range.split('-').filter_map(|n| n.parse().ok()).collect();
+            if let [start, end] = bounds[..] {
+                if start > end {
+                    issues.push(Issue::at(
+                        idx + 1,
+                        format!("{} ends before it starts", range),
+                    ));
+                }
+            }
+        }
+    }
+    issues
+}
+
+fn parse_range(input: &str) -> Interval<u32> {
+    let mut nums = input.split('-');
+    let left: u32 = nums.next().unwrap().parse().unwrap();
+    let right: u32 = nums.next().unwrap().parse().unwrap();
+    Interval::new(left.min(right), left.max(right))
+}
+
+// parse "6-7,7-9" to (6-7, 7-9)
+fn parse_row(input: &str) -> (Interval<u32>, Interval<u32>) {
+    let mut pairs = input.split(',');
+    let p1 = parse_range(pairs.next().unwrap());
+    let p2 = parse_range(pairs.next().unwrap());
+    (p1, p2)
+}
+
+pub fn part1(filepath: &str) -> u32 {
+    let content = fs::read_to_string(filepath).expect("expect file");
+    let lines = content.lines();
+    let mut count = 0;
+    for line in lines {
+        let (p1, p2) = parse_row(line);
+        if p1.contains_interval(&p2) || p2.contains_interval(&p1) {
+            count += 1;
+        }
+    }
+    count
+}
+
+pub fn part2(filepath: &str) -> u32 {
+    let content = fs::read_to_string(filepath).expect("expect file");
+    let lines = content.lines();
+    let mut count = 0;
+    for line in lines {
+        let (p1, p2) = parse_row(line);
+        if p1.overlaps(&p2) {
+            count += 1;
+        }
+    }
+    count
+}
+
+/// every elf's sections, in file order. elves `2n` and `2n + 1` share line `n`
+pub fn assignments(content: &str) -> Vec<Interval<u32>> {
+    content
+        .lines()
+        .flat_map(|line| {
+            let (p1, p2) = parse_row(line);
+            [p1, p2]
+        })
+        .collect()
+}
+
+/// all assignments sorted by start, laid out as an implicit binary search
+/// tree: the root of `items[lo..hi]` is the middle, and `max_end` holds the
+/// furthest end of the subtree rooted there
+pub struct Index {
+    items: Vec<(Interval<u32>, usize)>,
+    max_end: Vec<u32>,
+}
+
+impl Index {
+    pub fn new(assignments: &[Interval<u32>]) -> Self {
+        let mut items: Vec<_> = assignments.iter().copied().zip(0..).collect();
+        items.sort_unstable_by_key(|(i, elf)| (i.start, *elf));
+        let mut index = Index {
+            max_end: vec![0; items.len()],
+            items,
+        };
+        index.build(0, index.items.len());
+        index
+    }
+
+    fn build(&mut self, lo: usize, hi: usize) -> u32 {
+        if lo >= hi {
+            return 0;
+        }
+        let mid = (lo + hi) / 2;
+        let end = self.items[mid]
+            .0
+            .end
+            .max(self.build(lo, mid))
+            .max(self.build(mid + 1, hi));
+        self.max_end[mid] = end;
+        end
+    }
+
+    fn query(&self, lo: usize, hi: usize, sections: &Interval<u32>, out: &mut Vec<usize>) {
+        if lo >= hi {
+            return;
+        }
+        let mid = (lo + hi) / 2;
+        // nothing in here reaches far enough
+        if self.max_end[mid] < sections.start {
+            return;
+        }
+        self.query(lo, mid, sections, out);
+        let (interval, elf) = self.items[mid];
+        // neither this one nor anything after it starts early enough
+        if interval.start > sections.end {
+            return;
+        }
+        if interval.overlaps(sections) {
+            out.push(elf);
+        }
+        self.query(mid + 1, hi, sections, out);
+    }
+
+    /// the elves cleaning any of `sections`. the pruning skips subtrees that
+    /// can't match, but each of the k matches can still cost a walk down the
+    /// tree, so it's O(k log n) at worst
+    pub fn overlapping(&self, sections: Interval<u32>) -> Vec<usize> {
+        let mut out = Vec::new();
+        self.query(0, self.items.len(), &sections, &mut out);
+        out.sort_unstable();
+        out
+    }
+
+    pub fn covering(&self, section: u32) -> Vec<usize> {
+        self.overlapping(Interval::new(section, section))
+    }
+
+    /// the most elves cleaning one section at the same time, and the first
+    /// section where that happens
+    pub fn max_coverage(&self) -> Option<(usize, u32)> {
+        let mut events: Vec<(u64, i32)> = Vec::with_capacity(self.items.len() * 2);
+        for (i, _) in self.items.iter() {
+            events.push((i.start as u64, 1));
+            events.push((i.end as u64 + 1, -1));
+        }
+        // ends before starts on the same section
+        events.sort_unstable();
+
+        let mut best: Option<(usize, u32)> = None;
+        let mut current = 0;
+        for (section, delta) in events {
+            current += delta;
+            if delta > 0 && best.is_none_or(|(count, _)| current as usize > count) {
+                best = Some((current as usize, section as u32));
+            }
+        }
+        best
+    }
+
+    /// every two elves from different lines cleaning a shared section,
+    /// `(a, b)` with `a < b`, in O((n + k) log n)
+    pub fn cross_pair_overlaps(&self) -> Vec<(usize, usize)> {
+        let mut pairs = Vec::new();
+        let mut found = Vec::new();
+        for (interval, a) in self.items.iter() {
+            found.clear();
+            self.query(0, self.items.len(), interval, &mut found);
+            pairs.extend(
+                found
+                    .iter()
+                    .filter(|b| **b > *a && **b / 2 != *a / 2)
+                    .map(|b| (*a, *b)),
+            );
+        }
+        pairs.sort_unstable();
+        pairs
+    }
+}
+
+const REPORT_ELVES: usize = 10;
+
+pub fn report(filepath: &str) -> String {
+    let content = fs::read_to_string(filepath).expect("expect file");
+    let assignments = assignments(&content);
+    let index = Index::new(&assignments);
+    let mut out = format!(
+        "{} elves on {} lines\n",
+        assignments.len(),
+        assignments.len() / 2
+    );
+    if let Some((count, section)) = index.max_coverage() {
+        out.push_str(&format!(
+            "up to {} elves clean the same section, first at section {}\n",
+            count, section
+        ));
+        // elves numbered from 1 in file order, only the first few listed
+        let covering = index.covering(section);
+        let mut elves: Vec<String> = covering
+            .iter()
+            .take(REPORT_ELVES)
+            .map(|elf| format!("#{} ({})", elf + 1, assignments[*elf]))
+            .collect();
+        if covering.len() > REPORT_ELVES {
+            elves.push(format!("… and {} more", covering.len() - REPORT_ELVES));
+        }
+        out.push_str(&format!("section {}: {}\n", section, elves.join(", ")));
+    }
+    out.push_str(&format!(
+        "{} pairs of elves from different lines share sections\n",
+        index.cross_pair_overlaps().len()
+    ));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        assert_eq!(573, part1("data/2022/day4.txt"));
+    }
+
+    #[test]
+    fn test_part_two() {
+        assert_eq!(867, part2("data/2022/day4.txt"));
+    }
+
+    #[test]
+    fn test_reversed() {
+        assert_eq!(
+            vec![Interval::new(3, 5), Interval::new(4, 4)],
+            assignments("5-3,4-4\n")
+        );
+        assert_eq!(
+            vec![Issue::at(2, "5-3 ends before it starts")],
+            check_input("1-2,3-4\n5-3,4-4\n")
+        );
+    }
+
+    // brute force over every two elves
+    fn naive_pairs(assignments: &[Interval<u32>]) -> Vec<(usize, usize)> {
+        let mut pairs = Vec::new();
+        for a in 0..assignments.len() {
+            for b in a + 1..assignments.len() {
+                if a / 2 != b / 2 && assignments[a].overlaps(&assignments[b]) {
+                    pairs.push((a, b));
+                }
+            }
+        }
+        pairs
+    }
+
+    #[test]
+    fn test_index() {
+        let content = fs::read_to_string("data/2022/day4-sample.txt").unwrap();
+        let assignments = assignments(&content);
+        let index = Index::new(&assignments);
+
+        // 2-4,6-8 / 2-3,4-5 / 5-7,7-9 / 2-8,3-7 / 6-6,4-6 / 2-6,4-8
+        assert_eq!(vec![0, 3, 6, 7, 9, 10, 11], index.covering(4));
+        assert_eq!(vec![5], index.overlapping(Interval::new(9, 12)));
+        assert_eq!(Vec::<usize>::new(), index.covering(10));
+        assert_eq!(Some((8, 6)), index.max_coverage());
+        assert_eq!(naive_pairs(&assignments), index.cross_pair_overlaps());
+
+        let content = fs::read_to_string("data/2022/day4.txt").unwrap();
+        let all = super::assignments(&content);
+        let index = Index::new(&all);
+        assert_eq!(naive_pairs(&all), index.cross_pair_overlaps());
+        for section in [1, 50, 99] {
+            let naive: Vec<usize> = (0..all.len())
+                .filter(|elf| all[*elf].contains(section))
+                .collect();
+            assert_eq!(naive, index.covering(section));
+        }
+    }
+
+    #[test]
+    fn test_report() {
+        let out = report("data/2022/day4-sample.txt");
+        assert!(out.contains("section 6: #2 (6-8), #5 (5-7),"));
+        let out = report("data/2022/day4.txt");
+        assert!(out.contains(", … and 1078 more\n"));
+    }
+}
@@ -0,0 +1,870 @@
+use std::fmt;
+use std::fs;
+use std::str::FromStr;
+
+use crate::interval::Interval;
+use crate::lint::{Grammar, Issue};
+
+pub const VERSION: &str = "3";
+
+pub const GRAMMAR: Grammar = Grammar {
+    lines: &[
+        // crates can have longer names, anything but spaces and brackets
+        "( *\\[[^ \\[\\]]+\\])* *",
+        "[ 0-9]+",
+        "",
+        "move {uint} from {uint} to {uint}",
+    ],
+    check: Some(check_input),
+};
+
+fn is_label_line(line: &str) -> bool {
+    line.bytes().all(|b| b == b' ' || b.is_ascii_digit()) && line.bytes().any(|b| b != b' ')
+}
+
+// the drawing has to end with the stack labels, and moves can only use those
+fn check_input(input: &str) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    let mut stack_count = None;
+    for (idx, line) in input.lines().enumerate() {
+        let line_no = idx + 1;
+        if line.starts_with("move") {
+            let n = match stack_count {
+                Some(n) => n,
+                None => {
+                    issues.push(Issue::at(line_no, "move before the stack labels"));
+                    continue;
+                }
+            };
+            let m: Move = match line.parse() {
+                Ok(m) => m,
+                Err(e) => {
+                    issues.push(Issue::at(line_no, e.to_string()));
+                    continue;
+                }
+            };
+            for pos in [m.from, m.to] {
+                if pos == 0 || pos > n {
+                    issues.push(Issue::at(line_no, format!("there is no stack {}", pos)));
+                }
+            }
+        } else if is_label_line(line) {
+            let labels: Vec<&str> = line.split_whitespace().collect();
+            let expected: Vec<String> = (1..=labels.len()).map(|i| i.to_string()).collect();
+            if labels != expected {
+                issues.push(Issue::at(line_no, "stacks should be labelled 1, 2, 3 ..."));
+            }
+            if stack_count.is_some() {
+                issues.push(Issue::at(line_no, "more than one label line"));
+            }
+            stack_count = Some(labels.len());
+        } else if !line.trim().is_empty() && stack_count.is_some() {
+            issues.push(Issue::at(line_no, "crates after the stack labels"));
+        }
+    }
+    if stack_count.is_none() {
+        issues.push(Issue::file(
+            "missing the stack label line, e.g. \" 1   2   3\"",
+        ));
+    }
+    issues
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Drawing {
+    // as written on the label line, e.g. "1", "2", ... "10"
+    pub labels: Vec<String>,
+    // bottom crate first
+    pub stacks: Vec<Vec<String>>,
+}
+
+// lines and columns are 1-based
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DrawingError {
+    MissingLabels,
+    Unclosed { line: usize, column: usize },
+    Unexpected { line: usize, column: usize },
+    // a crate that isn't above exactly one label
+    Misaligned { line: usize, column: usize },
+    // a crate with nothing under it
+    Floating { line: usize, column: usize },
+}
+
+impl fmt::Display for DrawingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DrawingError::MissingLabels => write!(f, "missing the stack label line"),
+            DrawingError::Unclosed { line, column } => {
+                write!(f, "line {}, column {}: crate isn't closed", line, column)
+            }
+            DrawingError::Unexpected { line, column } => {
+                write!(f, "line {}, column {}: expected a crate", line, column)
+            }
+            DrawingError::Misaligned { line, column } => write!(
+                f,
+                "line {}, column {}: crate isn't above a single stack label",
+                line, column
+            ),
+            DrawingError::Floating { line, column } => {
+                write!(
+                    f,
+                    "line {}, column {}: crate has nothing under it",
+                    line, column
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for DrawingError {}
+
+// every whitespace separated word with the columns it spans
+fn words(line: &str) -> Vec<(Interval<usize>, &str)> {
+    let mut words = Vec::new();
+    let mut start = None;
+    for (idx, b) in line.bytes().chain(*b" ").enumerate() {
+        match (b == b' ', start) {
+            (false, None) => start = Some(idx),
+            (true, Some(s)) => {
+                words.push((Interval::new(s, idx - 1), &line[s..idx]));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    words
+}
+
+// every "[name]" with the columns it spans
+fn crates(line: &str, line_no: usize) -> Result<Vec<(Interval<usize>, &str)>, DrawingError> {
+    let bytes = line.as_bytes();
+    let mut crates = Vec::new();
+    let mut idx = 0;
+    while idx < bytes.len() {
+        match bytes[idx] {
+            b' ' => idx += 1,
+            b'[' => {
+                let column = idx + 1;
+                let len = match bytes[idx..].iter().position(|b| *b == b']') {
+                    Some(len) => len,
+                    None => {
+                        return Err(DrawingError::Unclosed {
+                            line: line_no,
+                            column,
+                        })
+                    }
+                };
+                let name = &line[idx + 1..idx + len];
+                if name.is_empty() || name.contains([' ', '[']) {
+                    return Err(DrawingError::Unexpected {
+                        line: line_no,
+                        column,
+                    });
+                }
+                crates.push((Interval::new(idx, idx + len), name));
+                idx += len + 1;
+            }
+            _ => {
+                return Err(DrawingError::Unexpected {
+                    line: line_no,
+                    column: idx + 1,
+                })
+            }
+        }
+    }
+    Ok(crates)
+}
+
+/// parse the drawing at the top of the input, up to and including the label
+/// line. a crate belongs to the label under it, so stacks can be any width
+/// apart and crates can have longer names
+///
+///     [D]
+/// [N] [C]
+/// [Z] [M] [P]
+///  1   2   3
+///
+/// becomes [["Z", "N"], ["M", "C", "D"], ["P"]], and the number of lines read
+pub fn parse_drawing(lines: &[&str]) -> Result<(Drawing, usize), DrawingError> {
+    let label_idx = lines
+        .iter()
+        .position(|line| is_label_line(line))
+        .ok_or(DrawingError::MissingLabels)?;
+    let labels = words(lines[label_idx]);
+    let mut stacks: Vec<Vec<String>> = vec![Vec::new(); labels.len()];
+
+    // bottom up, the crate at `height` has to land on a stack that tall
+    for (height, idx) in (0..label_idx).rev().enumerate() {
+        let line_no = idx + 1;
+        for (span, name) in crates(lines[idx], line_no)? {
+            let column = span.start + 1;
+            let mut under = labels
+                .iter()
+                .enumerate()
+                .filter(|(_, (l, _))| l.overlaps(&span));
+            let stack = match (under.next(), under.next()) {
+                (Some((stack, _)), None) => stack,
+                _ => {
+                    return Err(DrawingError::Misaligned {
+                        line: line_no,
+                        column,
+                    })
+                }
+            };
+            if stacks[stack].len() != height {
+                return Err(DrawingError::Floating {
+                    line: line_no,
+                    column,
+                });
+            }
+            stacks[stack].push(name.to_owned());
+        }
+    }
+
+    let labels = labels.into_iter().map(|(_, l)| l.to_owned()).collect();
+    Ok((Drawing { labels, stacks }, label_idx + 1))
+}
+
+impl Drawing {
+    /// the top crate of every stack
+    pub fn top(&self) -> Result<String, RunError> {
+        let mut r = String::new();
+        for (v, label) in self.stacks.iter().zip(self.labels.iter()) {
+            match v.last() {
+                Some(name) => r.push_str(name),
+                None => return Err(RunError::EmptyStack(label.clone())),
+            }
+        }
+        Ok(r)
+    }
+
+    /// back in the puzzle's format, every stack as wide as its widest crate
+    pub fn render(&self) -> String {
+        let widths: Vec<usize> = self
+            .stacks
+            .iter()
+            .zip(self.labels.iter())
+            .map(|(stack, label)| {
+                let widest = stack.iter().map(|c| c.len() + 2).max().unwrap_or(3);
+                widest.max(label.len())
+            })
+            .collect();
+        let centered = |s: &str, width: usize| {
+            let left = (width - s.len()) / 2;
+            format!(
+                "{}{}{}",
+                " ".repeat(left),
+                s,
+                " ".repeat(width - s.len() - left)
+            )
+        };
+
+        let mut out = String::new();
+        let height = self.stacks.iter().map(|s| s.len()).max().unwrap_or(0);
+        for row in (0..height).rev() {
+            let cells: Vec<String> = self
+                .stacks
+                .iter()
+                .zip(widths.iter())
+                .map(|(stack, width)| match stack.get(row) {
+                    Some(name) => centered(&format!("[{}]", name), *width),
+                    None => " ".repeat(*width),
+                })
+                .collect();
+            out.push_str(cells.join(" ").trim_end());
+            out.push('\n');
+        }
+        let labels: Vec<String> = self
+            .labels
+            .iter()
+            .zip(widths.iter())
+            .map(|(label, width)| centered(label, *width))
+            .collect();
+        out.push_str(labels.join(" ").trim_end());
+        out.push('\n');
+        out
+    }
+}
+
+/// "move 1 from 2 to 3", stacks are 1-based as in the input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub count: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseMoveError(String);
+
+impl fmt::Display for ParseMoveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid move {:?}, expected \"move N from A to B\"",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for ParseMoveError {}
+
+impl FromStr for Move {
+    type Err = ParseMoveError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseMoveError(s.to_owned());
+        match s.split_whitespace().collect::<Vec<_>>()[..] {
+            ["move", count, "from", from, "to", to] => Ok(Move {
+                count: count.parse().map_err(|_| err())?,
+                from: from.parse().map_err(|_| err())?,
+                to: to.parse().map_err(|_| err())?,
+            }),
+            _ => Err(err()),
+        }
+    }
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "move {} from {} to {}", self.count, self.from, self.to)
+    }
+}
+
+// lines are 1-based, stacks as in the input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RunError {
+    Drawing(DrawingError),
+    BadMove {
+        line: usize,
+        error: String,
+    },
+    NoStack {
+        line: usize,
+        stack: usize,
+    },
+    Underflow {
+        line: usize,
+        count: usize,
+        stack: usize,
+        height: usize,
+    },
+    // nothing to read off the top of a stack once every move is done
+    EmptyStack(String),
+}
+
+impl fmt::Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RunError::Drawing(e) => write!(f, "{}", e),
+            RunError::BadMove { line, error } => write!(f, "line {}: {}", line, error),
+            RunError::NoStack { line, stack } => {
+                write!(f, "line {}: there is no stack {}", line, stack)
+            }
+            RunError::Underflow {
+                line,
+                count,
+                stack,
+                height,
+            } => write!(
+                f,
+                "line {}: can't move {} crates off stack {}, it only has {}",
+                line, count, stack, height
+            ),
+            RunError::EmptyStack(label) => write!(f, "stack {} ends up empty", label),
+        }
+    }
+}
+
+impl std::error::Error for RunError {}
+
+pub trait Crane {
+    // `m` is checked to fit the stacks
+    fn apply(&self, stacks: &mut [Vec<String>], m: &Move);
+
+    /// apply `m`, read from `line`, if the stacks allow it
+    fn try_apply(&self, stacks: &mut [Vec<String>], line: usize, m: &Move) -> Result<(), RunError> {
+        for stack in [m.from, m.to] {
+            if stack == 0 || stack > stacks.len() {
+                return Err(RunError::NoStack { line, stack });
+            }
+        }
+        let height = stacks[m.from - 1].len();
+        if m.count > height {
+            return Err(RunError::Underflow {
+                line,
+                count: m.count,
+                stack: m.from,
+                height,
+            });
+        }
+        self.apply(stacks, m);
+        Ok(())
+    }
+}
+
+/// one crate at a time
+pub struct CrateMover9000;
+
+/// all the crates of a move at once, keeping their order
+pub struct CrateMover9001;
+
+/// up to `capacity` crates at a time, keeping the order of each lift
+pub struct CappedCrane {
+    capacity: usize,
+}
+
+impl CappedCrane {
+    pub fn new(capacity: usize) -> Self {
+        // a crane lifting nothing would never finish a move
+        assert!(capacity > 0, "crane capacity should be at least 1");
+        CappedCrane { capacity }
+    }
+}
+
+fn lift(stacks: &mut [Vec<String>], from: usize, to: usize, count: usize) {
+    let at = stacks[from].len() - count;
+    let mut crates = stacks[from].split_off(at);
+    stacks[to].append(&mut crates);
+}
+
+impl Crane for CrateMover9000 {
+    fn apply(&self, stacks: &mut [Vec<String>], m: &Move) {
+        CappedCrane::new(1).apply(stacks, m)
+    }
+}
+
+impl Crane for CrateMover9001 {
+    fn apply(&self, stacks: &mut [Vec<String>], m: &Move) {
+        lift(stacks, m.from - 1, m.to - 1, m.count)
+    }
+}
+
+impl Crane for CappedCrane {
+    fn apply(&self, stacks: &mut [Vec<String>], m: &Move) {
+        let mut left = m.count;
+        while left > 0 {
+            let count = left.min(self.capacity);
+            lift(stacks, m.from - 1, m.to - 1, count);
+            left -= count;
+        }
+    }
+}
+
+/// the drawing and its moves with their line numbers
+pub fn parse(content: &str) -> Result<(Drawing, Vec<(usize, Move)>), RunError> {
+    let lines: Vec<&str> = content.lines().collect();
+    let (drawing, read) = parse_drawing(&lines).map_err(RunError::Drawing)?;
+    let mut moves = Vec::new();
+    for (idx, line) in lines.iter().enumerate().skip(read) {
+        if line.trim().is_empty() {
+            continue;
+        }
+        match line.parse() {
+            Ok(m) => moves.push((idx + 1, m)),
+            Err(e) => {
+                return Err(RunError::BadMove {
+                    line: idx + 1,
+                    error: format!("{}", e),
+                })
+            }
+        }
+    }
+    Ok((drawing, moves))
+}
+
+/// steps through the moves and back again
+///
+/// every applied move logs the crates it took, in their order on the `from`
+/// stack. whatever the crane, those crates end up as the top `count` of `to`,
+/// so undoing a move puts them back without replaying anything
+pub struct Session<'a, C> {
+    crane: &'a C,
+    drawing: Drawing,
+    moves: &'a [(usize, Move)],
+    log: Vec<Vec<String>>,
+}
+
+impl<'a, C: Crane> Session<'a, C> {
+    pub fn new(crane: &'a C, drawing: Drawing, moves: &'a [(usize, Move)]) -> Self {
+        Session {
+            crane,
+            drawing,
+            moves,
+            log: Vec::new(),
+        }
+    }
+
+    pub fn drawing(&self) -> &Drawing {
+        &self.drawing
+    }
+
+    /// how many moves are applied
+    pub fn position(&self) -> usize {
+        self.log.len()
+    }
+
+    /// apply the next move, false once there are none left
+    pub fn forward(&mut self) -> Result<bool, RunError> {
+        let (line, m) = match self.moves.get(self.log.len()) {
+            Some(x) => x,
+            None => return Ok(false),
+        };
+        let taken = match self.drawing.stacks.get(m.from.wrapping_sub(1)) {
+            Some(stack) if m.count <= stack.len() => stack[stack.len() - m.count..].to_vec(),
+            _ => Vec::new(),
+        };
+        self.crane.try_apply(&mut self.drawing.stacks, *line, m)?;
+        self.log.push(taken);
+        Ok(true)
+    }
+
+    /// undo the last move, false when back at the start
+    pub fn back(&mut self) -> bool {
+        let taken = match self.log.pop() {
+            Some(taken) => taken,
+            None => return false,
+        };
+        let (_, m) = self.moves[self.log.len()];
+        let to = &mut self.drawing.stacks[m.to - 1];
+        to.truncate(to.len() - m.count);
+        self.drawing.stacks[m.from - 1].extend(taken);
+        true
+    }
+}
+
+/// the drawing after each move, stops after the first move that fails
+pub struct Trace<'a, C> {
+    session: Session<'a, C>,
+    failed: bool,
+}
+
+pub fn trace<'a, C: Crane>(
+    crane: &'a C,
+    drawing: Drawing,
+    moves: &'a [(usize, Move)],
+) -> Trace<'a, C> {
+    Trace {
+        session: Session::new(crane, drawing, moves),
+        failed: false,
+    }
+}
+
+impl<C: Crane> Iterator for Trace<'_, C> {
+    type Item = Result<Drawing, RunError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        match self.session.forward() {
+            Ok(true) => Some(Ok(self.session.drawing().clone())),
+            Ok(false) => None,
+            Err(e) => {
+                self.failed = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+pub fn run(crane: &impl Crane, content: &str) -> Result<String, RunError> {
+    let (mut drawing, moves) = parse(content)?;
+    for (line, m) in moves.iter() {
+        crane.try_apply(&mut drawing.stacks, *line, m)?;
+    }
+    drawing.top()
+}
+
+fn process(crane: &impl Crane, filepath: &str) -> String {
+    let content = fs::read_to_string(filepath).expect("expect file");
+    match run(crane, &content) {
+        Ok(top) => top,
+        Err(e) => panic!("{}", e),
+    }
+}
+
+/// the final drawing with each crane
+pub fn report(filepath: &str) -> String {
+    let content = fs::read_to_string(filepath).expect("expect file");
+    let (drawing, moves) = match parse(&content) {
+        Ok(x) => x,
+        Err(e) => return format!("{}\n", e),
+    };
+    let mut out = String::new();
+    let last9000 = trace(&CrateMover9000, drawing.clone(), &moves).last();
+    let last9001 = trace(&CrateMover9001, drawing.clone(), &moves).last();
+    for (name, last) in [("CrateMover 9000", last9000), ("CrateMover 9001", last9001)] {
+        match last.unwrap_or_else(|| Ok(drawing.clone())) {
+            Ok(last) => {
+                out.push_str(&format!("after {} moves with the {}:\n", moves.len(), name));
+                out.push_str(&last.render());
+            }
+            Err(e) => out.push_str(&format!("the {} fails, {}\n", name, e)),
+        }
+    }
+    // run to the end, then undo back to the middle
+    let mut session = Session::new(&CrateMover9001, drawing, &moves);
+    while let Ok(true) = session.forward() {}
+    let middle = session.position() / 2;
+    while session.position() > middle && session.back() {}
+    out.push_str(&format!(
+        "halfway, after {} moves with the CrateMover 9001:\n",
+        session.position()
+    ));
+    out.push_str(&session.drawing().render());
+    out
+}
+
+pub fn part1(filepath: &str) -> String {
+    process(&CrateMover9000, filepath)
+}
+
+pub fn part2(filepath: &str) -> String {
+    process(&CrateMover9001, filepath)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = part1("data/2022/day5.txt");
+        assert_eq!("JDTMRWCQJ", input);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = part2("data/2022/day5.txt");
+        assert_eq!("VHJDDCWRD", input);
+    }
+
+    #[test]
+    fn test_parse_drawing() {
+        let stacks = |s: &[&[&str]]| -> Vec<Vec<String>> {
+            s.iter()
+                .map(|v| v.iter().map(|c| c.to_string()).collect())
+                .collect()
+        };
+
+        let content = fs::read_to_string("data/2022/day5-sample.txt").unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        let (drawing, read) = parse_drawing(&lines).unwrap();
+        assert_eq!(4, read);
+        assert_eq!(vec!["1", "2", "3"], drawing.labels);
+        assert_eq!(
+            stacks(&[&["Z", "N"], &["M", "C", "D"], &["P"]]),
+            drawing.stacks
+        );
+
+        // wide labels, long names and ragged trailing whitespace
+        let lines = [
+            "                                       [X]",
+            "[A]                                    [Y]   ",
+            "[B]    [CC] [D]                        [Z]",
+            " 1   2   3   4   5   6   7   8   9  10  11",
+        ];
+        let (drawing, _) = parse_drawing(&lines).unwrap();
+        assert_eq!(11, drawing.stacks.len());
+        assert_eq!(
+            stacks(&[&["B", "A"], &[], &["CC"], &["D"]]),
+            drawing.stacks[..4]
+        );
+        assert_eq!(stacks(&[&[], &["Z", "Y", "X"]]), drawing.stacks[9..]);
+
+        assert_eq!(
+            Err(DrawingError::MissingLabels),
+            parse_drawing(&["[A] [B]", "", "move 1 from 2 to 1"])
+        );
+        assert_eq!(
+            Err(DrawingError::Floating { line: 1, column: 5 }),
+            parse_drawing(&["    [C]", "[A]", " 1   2"])
+        );
+        assert_eq!(
+            Err(DrawingError::Misaligned { line: 1, column: 3 }),
+            parse_drawing(&["  [A]", " 1   2"])
+        );
+        assert_eq!(
+            Err(DrawingError::Unclosed { line: 1, column: 1 }),
+            parse_drawing(&["[A", " 1"])
+        );
+        assert_eq!(
+            "line 1, column 2: expected a crate",
+            parse_drawing(&[" A", " 1"]).unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_cranes() {
+        let content = fs::read_to_string("data/2022/day5-sample.txt").unwrap();
+        let (drawing, moves) = parse(&content).unwrap();
+        assert_eq!(
+            (
+                6,
+                Move {
+                    count: 1,
+                    from: 2,
+                    to: 1
+                }
+            ),
+            moves[0]
+        );
+        assert_eq!("move 1 from 2 to 1", moves[0].1.to_string());
+        assert!("move 1 from x to 1".parse::<Move>().is_err());
+
+        let top = |crane: &dyn Fn(&mut [Vec<String>], &Move)| {
+            let mut d = drawing.clone();
+            moves.iter().for_each(|(_, m)| crane(&mut d.stacks, m));
+            d.top().unwrap()
+        };
+        assert_eq!("CMZ", top(&|s, m| CrateMover9000.apply(s, m)));
+        assert_eq!("MCD", top(&|s, m| CrateMover9001.apply(s, m)));
+        assert_eq!("CMZ", top(&|s, m| CappedCrane::new(1).apply(s, m)));
+        assert_eq!("MCD", top(&|s, m| CappedCrane::new(3).apply(s, m)));
+        // moving 3 crates two at a time
+        assert_eq!("MCZ", top(&|s, m| CappedCrane::new(2).apply(s, m)));
+    }
+
+    #[test]
+    #[should_panic(expected = "at least 1")]
+    fn test_zero_capacity() {
+        CappedCrane::new(0);
+    }
+
+    #[test]
+    fn test_trace() {
+        let content = fs::read_to_string("data/2022/day5-sample.txt").unwrap();
+        let (drawing, moves) = parse(&content).unwrap();
+        let states: Vec<Drawing> = trace(&CrateMover9000, drawing.clone(), &moves)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(4, states.len());
+        assert_eq!(
+            "[D]\n[N] [C]\n[Z] [M] [P]\n 1   2   3\n",
+            states[0].render()
+        );
+        assert_eq!(Ok("CMZ".to_owned()), states[3].top());
+
+        // the rendering parses back to the same drawing
+        let rendered = drawing.render();
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(drawing, parse_drawing(&lines).unwrap().0);
+
+        let wide = Drawing {
+            labels: vec!["1".into(), "2".into(), "10".into()],
+            stacks: vec![vec!["AB".into()], vec![], vec!["C".into(), "D".into()]],
+        };
+        assert_eq!("         [D]\n[AB]     [C]\n 1    2  10\n", wide.render());
+        let rendered = wide.render();
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(wide, parse_drawing(&lines).unwrap().0);
+    }
+
+    #[test]
+    fn test_checked_run() {
+        let drawing = "[A]\n[B] [C]\n 1   2\n\n";
+        assert_eq!(
+            Ok("AC".to_owned()),
+            run(
+                &CrateMover9000,
+                &format!("{}move 1 from 1 to 2\nmove 1 from 2 to 1\n", drawing)
+            )
+        );
+        assert_eq!(
+            Err(RunError::Underflow {
+                line: 6,
+                count: 4,
+                stack: 1,
+                height: 3
+            }),
+            run(
+                &CrateMover9001,
+                &format!("{}move 1 from 2 to 1\nmove 4 from 1 to 2\n", drawing)
+            )
+        );
+        // the 9000 used to skip the missing crates
+        assert_eq!(
+            "line 5: can't move 3 crates off stack 2, it only has 1",
+            run(&CrateMover9000, &format!("{}move 3 from 2 to 1\n", drawing))
+                .unwrap_err()
+                .to_string()
+        );
+        assert_eq!(
+            Err(RunError::NoStack { line: 5, stack: 3 }),
+            run(&CrateMover9000, &format!("{}move 1 from 3 to 1\n", drawing))
+        );
+        assert_eq!(
+            Err(RunError::EmptyStack("2".to_owned())),
+            run(&CrateMover9000, &format!("{}move 1 from 2 to 1\n", drawing))
+        );
+        assert_eq!(
+            Err(RunError::BadMove {
+                line: 5,
+                error: "invalid move \"move one from 2 to 1\", expected \"move N from A to B\""
+                    .to_owned()
+            }),
+            run(
+                &CrateMover9000,
+                &format!("{}move one from 2 to 1\n", drawing)
+            )
+        );
+        assert_eq!(
+            Err(RunError::Drawing(DrawingError::MissingLabels)),
+            run(&CrateMover9000, "[A]\n\nmove 1 from 1 to 1\n")
+        );
+    }
+
+    #[test]
+    fn test_session() {
+        let content = fs::read_to_string("data/2022/day5-sample.txt").unwrap();
+        let (drawing, moves) = parse(&content).unwrap();
+        for crane in [&CappedCrane::new(1), &CappedCrane::new(2)] {
+            let mut session = Session::new(crane, drawing.clone(), &moves);
+            let mut states = vec![drawing.clone()];
+            while session.forward().unwrap() {
+                states.push(session.drawing().clone());
+            }
+            assert_eq!(4, session.position());
+            while session.back() {
+                assert_eq!(&states[session.position()], session.drawing());
+            }
+            assert_eq!(0, session.position());
+            assert!(session.forward().unwrap());
+            assert_eq!(&states[1], session.drawing());
+        }
+
+        // a failing move leaves the session where it was
+        let moves = [(1, "move 9 from 1 to 2".parse().unwrap())];
+        let mut session = Session::new(&CrateMover9001, drawing.clone(), &moves);
+        assert!(session.forward().is_err());
+        assert_eq!((0, &drawing), (session.position(), session.drawing()));
+    }
+
+    #[test]
+    fn test_check_input() {
+        assert!(check_input(&fs::read_to_string("data/2022/day5-sample.txt").unwrap()).is_empty());
+        assert_eq!(
+            vec![
+                Issue::at(4, "move before the stack labels"),
+                Issue::file("missing the stack label line, e.g. \" 1   2   3\""),
+            ],
+            check_input("    [D]\n[N] [C]\n\nmove 1 from 2 to 1\n")
+        );
+        assert_eq!(
+            vec![Issue::at(4, "there is no stack 3")],
+            check_input("[N] [C]\n 1   2\n\nmove 1 from 3 to 1\n")
+        );
+    }
+
+    #[test]
+    fn test_grammar() {
+        // whatever `parse_drawing` takes, lint takes too
+        let content = "    [10]\n[ab][C]  [x]\n 1   2   3\n\nmove 1 from 2 to 1\n";
+        let lines: Vec<&str> = content.lines().collect();
+        assert!(parse_drawing(&lines).is_ok());
+        assert_eq!(Vec::<Issue>::new(), crate::lint::lint(&GRAMMAR, content));
+        assert_eq!(1, crate::lint::lint(&GRAMMAR, "[a b]\n 1\n").len());
+        assert_eq!(1, crate::lint::lint(&GRAMMAR, "[]\n 1\n").len());
+    }
+}
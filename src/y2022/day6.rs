@@ -0,0 +1,305 @@
+use std::fs::File;
+use std::io::{self, Read};
+
+use crate::lint::Grammar;
+
+pub const VERSION: &str = "3";
+
+pub const GRAMMAR: Grammar = Grammar {
+    lines: &["[a-z]+"],
+    check: None,
+};
+
+/// the last `size` bytes of a stream, and how many byte values repeat in them
+pub struct Window {
+    size: usize,
+    counts: [u32; 256],
+    ring: Vec<u8>,
+    // bytes pushed so far
+    len: u64,
+    repeated: usize,
+}
+
+impl Window {
+    pub fn new(size: usize) -> Self {
+        assert!(size > 0, "window size should be at least 1");
+        Window {
+            size,
+            counts: [0; 256],
+            ring: vec![0; size],
+            len: 0,
+            repeated: 0,
+        }
+    }
+
+    /// push the next byte, true when the last `size` bytes are all different
+    pub fn push(&mut self, b: u8) -> bool {
+        let slot = (self.len % self.size as u64) as usize;
+        if self.len >= self.size as u64 {
+            let old = self.ring[slot] as usize;
+            self.counts[old] -= 1;
+            if self.counts[old] == 1 {
+                self.repeated -= 1;
+            }
+        }
+        self.ring[slot] = b;
+        self.counts[b as usize] += 1;
+        if self.counts[b as usize] == 2 {
+            self.repeated += 1;
+        }
+        self.len += 1;
+        self.len >= self.size as u64 && self.repeated == 0
+    }
+
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+}
+
+const CHUNK: usize = 64 * 1024;
+
+// the signal is the first line, the line ending isn't part of it
+fn is_line_end(b: u8) -> bool {
+    b == b'\n' || b == b'\r'
+}
+
+/// every position where the `size` bytes before it are all different, read
+/// from `reader` in chunks, so the stream doesn't have to fit in memory.
+/// stops at the end of the first line
+pub struct Markers<R> {
+    reader: R,
+    buf: Vec<u8>,
+    pos: usize,
+    filled: usize,
+    window: Window,
+    done: bool,
+}
+
+pub fn markers<R: Read>(reader: R, size: usize) -> Markers<R> {
+    Markers {
+        reader,
+        buf: vec![0; CHUNK],
+        pos: 0,
+        filled: 0,
+        window: Window::new(size),
+        done: false,
+    }
+}
+
+impl<R: Read> Iterator for Markers<R> {
+    type Item = io::Result<u64>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            while self.pos < self.filled {
+                let b = self.buf[self.pos];
+                self.pos += 1;
+                if is_line_end(b) {
+                    self.done = true;
+                    return None;
+                }
+                if self.window.push(b) {
+                    return Some(Ok(self.window.len()));
+                }
+            }
+            if self.done {
+                return None;
+            }
+            match self.reader.read(&mut self.buf) {
+                Ok(0) => return None,
+                Ok(n) => {
+                    self.pos = 0;
+                    self.filled = n;
+                }
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+/// how many bytes are read up to the end of the first marker, if there is one
+pub fn detect<R: Read>(reader: R, size: usize) -> io::Result<Option<u64>> {
+    markers(reader, size).next().transpose()
+}
+
+/// the length of the longest run of all different bytes on the first line
+pub fn longest_distinct_run<R: Read>(mut reader: R) -> io::Result<u64> {
+    // 1-based position of the last time each byte was seen, 0 for never
+    let mut last = [0u64; 256];
+    let mut buf = vec![0; CHUNK];
+    let (mut pos, mut start, mut longest) = (0u64, 0u64, 0u64);
+    loop {
+        let n = match reader.read(&mut buf) {
+            Ok(0) => return Ok(longest),
+            Ok(n) => n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        for b in &buf[..n] {
+            if is_line_end(*b) {
+                return Ok(longest);
+            }
+            pos += 1;
+            // the run restarts right after the previous copy of `b`
+            start = start.max(last[*b as usize]);
+            last[*b as usize] = pos;
+            longest = longest.max(pos - start);
+        }
+    }
+}
+
+fn detect_file(filepath: &str, size: usize) -> u64 {
+    let file = File::open(filepath).expect("expect file");
+    match detect(file, size).expect("expect readable file") {
+        Some(pos) => pos,
+        None => panic!("no {} different characters in a row", size),
+    }
+}
+
+pub fn report(filepath: &str) -> String {
+    let open = || File::open(filepath).expect("expect file");
+    let mut out = String::new();
+    for (name, size) in [("start-of-packet", 4), ("start-of-message", 14)] {
+        let all: Vec<u64> = markers(open(), size)
+            .collect::<io::Result<_>>()
+            .expect("expect readable file");
+        match all.first() {
+            Some(first) => out.push_str(&format!(
+                "first {} marker after {}, {} in total\n",
+                name,
+                first,
+                all.len()
+            )),
+            None => out.push_str(&format!("no {} marker\n", name)),
+        }
+    }
+    let longest = longest_distinct_run(open()).expect("expect readable file");
+    out.push_str(&format!(
+        "longest run of different characters: {}\n",
+        longest
+    ));
+    out
+}
+
+// 1093
+pub fn part1(filepath: &str) -> u64 {
+    detect_file(filepath, 4)
+}
+
+// 3534
+pub fn part2(filepath: &str) -> u64 {
+    detect_file(filepath, 14)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn process(distinct_count: u32, bytes: &[u8]) -> Option<u32> {
+        detect(bytes, distinct_count as usize)
+            .unwrap()
+            .map(|pos| pos as u32)
+    }
+
+    #[test]
+    fn test_part1() {
+        let bytes = Vec::from(*b"bvwbjplbgvbhsrlpgdmjqwftvncz");
+        assert_eq!(Some(5), process(4, &bytes));
+        let bytes = Vec::from(*b"nppdvjthqldpwncqszvftbrmjlhg");
+        assert_eq!(Some(6), process(4, &bytes));
+        let bytes = Vec::from(*b"nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg");
+        assert_eq!(Some(10), process(4, &bytes));
+        let bytes = Vec::from(*b"zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw");
+        assert_eq!(Some(11), process(4, &bytes));
+    }
+
+    #[test]
+    fn test_part2() {
+        let bytes = Vec::from(*b"mjqjpqmgbljsphdztnvjfqwrcgsmlb");
+        assert_eq!(Some(19), process(14, &bytes));
+        let bytes = Vec::from(*b"bvwbjplbgvbhsrlpgdmjqwftvncz");
+        assert_eq!(Some(23), process(14, &bytes));
+        let bytes = Vec::from(*b"nppdvjthqldpwncqszvftbrmjlhg");
+        assert_eq!(Some(23), process(14, &bytes));
+        let bytes = Vec::from(*b"nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg");
+        assert_eq!(Some(29), process(14, &bytes));
+        let bytes = Vec::from(*b"zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw");
+        assert_eq!(Some(26), process(14, &bytes));
+    }
+
+    // reads one byte at a time, to cross every chunk boundary
+    struct Trickle<'a>(&'a [u8]);
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            match self.0.split_first() {
+                Some((b, rest)) if !buf.is_empty() => {
+                    buf[0] = *b;
+                    self.0 = rest;
+                    Ok(1)
+                }
+                _ => Ok(0),
+            }
+        }
+    }
+
+    #[test]
+    fn test_detect() {
+        let signal = b"mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+        assert_eq!(Some(7), detect(Trickle(signal), 4).unwrap());
+        assert_eq!(Some(19), detect(Trickle(signal), 14).unwrap());
+        assert_eq!(Some(1), detect(Trickle(signal), 1).unwrap());
+        assert_eq!(None, detect(Trickle(signal), 27).unwrap());
+        // a marker on the very last byte isn't the same as none
+        assert_eq!(Some(4), detect(Trickle(b"abcd"), 4).unwrap());
+        assert_eq!(None, detect(Trickle(b"abca"), 4).unwrap());
+
+        // a long run of repeats before the marker, larger than one chunk
+        let mut signal = vec![b'a'; 3 * CHUNK + 5];
+        signal.extend(b"abcd");
+        assert_eq!(Some(signal.len() as u64), detect(&signal[..], 4).unwrap());
+        assert_eq!(1093, part1("data/2022/day6.txt"));
+        assert_eq!(3534, part2("data/2022/day6.txt"));
+    }
+
+    #[test]
+    fn test_line_end() {
+        // the newline would complete a marker if it counted
+        assert_eq!(None, detect(&b"abca\n"[..], 4).unwrap());
+        assert_eq!(None, detect(Trickle(b"abca\r\n"), 4).unwrap());
+        assert_eq!(Some(4), detect(&b"abcd\nefgh\n"[..], 4).unwrap());
+        let all: Vec<u64> = markers(&b"abcd\nefgh"[..], 4).map(|m| m.unwrap()).collect();
+        assert_eq!(vec![4], all);
+        assert_eq!(3, longest_distinct_run(&b"abca\n"[..]).unwrap());
+
+        let path = std::env::temp_dir().join(format!("aoc-day6-{}.txt", std::process::id()));
+        fs::write(&path, "abca\n").unwrap();
+        let filepath = path.to_string_lossy().into_owned();
+        assert_eq!(None, detect(File::open(&path).unwrap(), 4).unwrap());
+        assert!(report(&filepath).starts_with("no start-of-packet marker\n"));
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_markers() {
+        let all: Vec<u64> = markers(Trickle(b"aabcdeef"), 4)
+            .map(|m| m.unwrap())
+            .collect();
+        assert_eq!(vec![5, 6], all);
+        let all: Vec<u64> = markers(&b"abcabc"[..], 3).map(|m| m.unwrap()).collect();
+        assert_eq!(vec![3, 4, 5, 6], all);
+
+        assert_eq!(5, longest_distinct_run(Trickle(b"aabcdeef")).unwrap());
+        assert_eq!(1, longest_distinct_run(&b"aaaa"[..]).unwrap());
+        assert_eq!(0, longest_distinct_run(&b""[..]).unwrap());
+        let signal = b"mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+        assert_eq!(
+            Some(longest_distinct_run(&signal[..]).unwrap()),
+            (1..30)
+                .rev()
+                .find(|size| detect(&signal[..], *size as usize).unwrap().is_some())
+        );
+    }
+}
@@ -0,0 +1,837 @@
+use std::fmt;
+use std::fs;
+
+use crate::lint::Grammar;
+
+pub const VERSION: &str = "3";
+
+pub const GRAMMAR: Grammar = Grammar {
+    lines: &["\\$ cd {name}", "\\$ ls", "dir {name}", "{uint} {name}"],
+    check: None,
+};
+
+#[derive(Debug)]
+enum Token {
+    Cd(String),
+    Ls,
+    Dir(String),
+    FileStat { size: u64, name: String },
+}
+
+// lines are 1-based
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShellError {
+    Unsupported { line: usize, command: String },
+    Malformed { line: usize, text: String },
+    // a listing without an `ls` before it
+    UnexpectedOutput { line: usize },
+    NotADirectory { line: usize, path: String },
+    // a file and a directory with the same name
+    Conflict { line: usize, path: String },
+}
+
+impl fmt::Display for ShellError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShellError::Unsupported { line, command } => {
+                write!(f, "line {}: unsupported command {:?}", line, command)
+            }
+            ShellError::Malformed { line, text } => {
+                write!(f, "line {}: can't make sense of {:?}", line, text)
+            }
+            ShellError::UnexpectedOutput { line } => {
+                write!(f, "line {}: output without an ls", line)
+            }
+            ShellError::NotADirectory { line, path } => {
+                write!(f, "line {}: {} isn't a directory", line, path)
+            }
+            ShellError::Conflict { line, path } => {
+                write!(
+                    f,
+                    "line {}: {} is listed as a file and a directory",
+                    line, path
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ShellError {}
+
+fn parse_line(line: &str, line_no: usize) -> Result<Token, ShellError> {
+    let malformed = || ShellError::Malformed {
+        line: line_no,
+        text: line.to_owned(),
+    };
+    if let Some(command) = line.strip_prefix('$') {
+        return match command.split_whitespace().collect::<Vec<_>>()[..] {
+            ["cd", target] => Ok(Token::Cd(target.to_owned())),
+            ["ls"] => Ok(Token::Ls),
+            _ => Err(ShellError::Unsupported {
+                line: line_no,
+                command: command.trim().to_owned(),
+            }),
+        };
+    }
+    match line.split_whitespace().collect::<Vec<_>>()[..] {
+        ["dir", name] => Ok(Token::Dir(name.to_owned())),
+        [size, name] => Ok(Token::FileStat {
+            size: size.parse().map_err(|_| malformed())?,
+            name: name.to_owned(),
+        }),
+        _ => Err(malformed()),
+    }
+}
+
+fn tokenize(cnt: &str) -> Result<Vec<(usize, Token)>, ShellError> {
+    cnt.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| Ok((idx + 1, parse_line(line, idx + 1)?)))
+        .collect()
+}
+
+pub type NodeId = usize;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Kind {
+    Dir { children: Vec<NodeId> },
+    File { size: u64 },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Node {
+    pub name: String,
+    // None for the root only
+    pub parent: Option<NodeId>,
+    pub kind: Kind,
+}
+
+impl Node {
+    pub fn is_dir(&self) -> bool {
+        matches!(self.kind, Kind::Dir { .. })
+    }
+}
+
+/// every directory and file of the transcript, stored in one `Vec` and
+/// pointing at each other by index. a node is always added after its parent
+#[derive(Debug, Clone)]
+pub struct Fs {
+    nodes: Vec<Node>,
+}
+
+impl Fs {
+    pub const ROOT: NodeId = 0;
+
+    pub fn new() -> Self {
+        Fs {
+            nodes: vec![Node {
+                name: "/".to_owned(),
+                parent: None,
+                kind: Kind::Dir {
+                    children: Vec::new(),
+                },
+            }],
+        }
+    }
+
+    pub fn node(&self, id: NodeId) -> &Node {
+        &self.nodes[id]
+    }
+
+    pub fn children(&self, dir: NodeId) -> &[NodeId] {
+        match &self.nodes[dir].kind {
+            Kind::Dir { children } => children,
+            Kind::File { .. } => &[],
+        }
+    }
+
+    pub fn child(&self, dir: NodeId, name: &str) -> Option<NodeId> {
+        self.children(dir)
+            .iter()
+            .copied()
+            .find(|c| self.nodes[*c].name == name)
+    }
+
+    fn add(&mut self, parent: NodeId, name: &str, kind: Kind) -> NodeId {
+        let id = self.nodes.len();
+        match &mut self.nodes[parent].kind {
+            Kind::Dir { children } => children.push(id),
+            Kind::File { .. } => panic!("{} isn't a directory", self.path(parent)),
+        }
+        self.nodes.push(Node {
+            name: name.to_owned(),
+            parent: Some(parent),
+            kind,
+        });
+        id
+    }
+
+    /// the directory `name` in `parent`, created if it isn't there yet
+    pub fn add_dir(&mut self, parent: NodeId, name: &str) -> NodeId {
+        match self.child(parent, name) {
+            Some(id) => id,
+            None => self.add(
+                parent,
+                name,
+                Kind::Dir {
+                    children: Vec::new(),
+                },
+            ),
+        }
+    }
+
+    /// the file `name` in `parent`, with its size updated if it's there already
+    pub fn add_file(&mut self, parent: NodeId, name: &str, size: u64) -> NodeId {
+        match self.child(parent, name) {
+            Some(id) => {
+                self.nodes[id].kind = Kind::File { size };
+                id
+            }
+            None => self.add(parent, name, Kind::File { size }),
+        }
+    }
+
+    /// "/a/e" for the directory e in a
+    pub fn path(&self, id: NodeId) -> String {
+        let mut names = Vec::new();
+        let mut current = Some(id);
+        while let Some(id) = current {
+            if id != Fs::ROOT {
+                names.push(&self.nodes[id].name[..]);
+            }
+            current = self.nodes[id].parent;
+        }
+        names.reverse();
+        format!("/{}", names.join("/"))
+    }
+
+    /// the node at an absolute path such as "/a/e"
+    pub fn lookup(&self, path: &str) -> Option<NodeId> {
+        path.split('/')
+            .filter(|name| !name.is_empty())
+            .try_fold(Fs::ROOT, |dir, name| self.child(dir, name))
+    }
+
+    /// every node depth first, parents before their children, with its depth
+    pub fn iter(&self) -> impl Iterator<Item = (NodeId, usize)> + '_ {
+        let mut stack = vec![(Fs::ROOT, 0)];
+        std::iter::from_fn(move || {
+            let (id, depth) = stack.pop()?;
+            stack.extend(self.children(id).iter().rev().map(|c| (*c, depth + 1)));
+            Some((id, depth))
+        })
+    }
+
+    pub fn dirs(&self) -> impl Iterator<Item = NodeId> + '_ {
+        (0..self.nodes.len()).filter(|id| self.nodes[*id].is_dir())
+    }
+
+    /// like `du`: the size of every node, directories counting everything
+    /// under them, indexed by `NodeId`
+    pub fn sizes(&self) -> Vec<u64> {
+        let mut sizes: Vec<u64> = self
+            .nodes
+            .iter()
+            .map(|n| match n.kind {
+                Kind::File { size } => size,
+                Kind::Dir { .. } => 0,
+            })
+            .collect();
+        // children come after their parent
+        for id in (1..self.nodes.len()).rev() {
+            if let Some(parent) = self.nodes[id].parent {
+                sizes[parent] += sizes[id];
+            }
+        }
+        sizes
+    }
+}
+
+// part 1 adds up every directory up to this size, the renderers mark them
+pub const SMALL_DIR: u64 = 100000;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RenderOptions {
+    // largest first, otherwise in the order of the transcript
+    pub sort_by_size: bool,
+    // the root is at depth 0
+    pub max_depth: Option<usize>,
+}
+
+impl Fs {
+    // the children of `dir` in the order asked for
+    fn sorted_children(&self, dir: NodeId, sizes: &[u64], opts: &RenderOptions) -> Vec<NodeId> {
+        let mut children = self.children(dir).to_vec();
+        if opts.sort_by_size {
+            // stable, so equal sizes stay in transcript order
+            children.sort_by(|a, b| sizes[*b].cmp(&sizes[*a]));
+        }
+        children
+    }
+
+    fn mark(&self, id: NodeId, sizes: &[u64]) -> &'static str {
+        if self.nodes[id].is_dir() && sizes[id] <= SMALL_DIR {
+            " *"
+        } else {
+            ""
+        }
+    }
+
+    /// like the puzzle's listing, directories up to `SMALL_DIR` marked with *
+    ///
+    /// - / (dir, size=48381165)
+    ///   - a (dir, size=94853) *
+    ///     - e (dir, size=584) *
+    ///       - i (file, size=584)
+    pub fn render_tree(&self, opts: &RenderOptions) -> String {
+        let sizes = self.sizes();
+        let mut out = String::new();
+        let mut stack = vec![(Fs::ROOT, 0)];
+        while let Some((id, depth)) = stack.pop() {
+            let node = &self.nodes[id];
+            let kind = if node.is_dir() { "dir" } else { "file" };
+            out.push_str(&format!(
+                "{}- {} ({}, size={}){}\n",
+                "  ".repeat(depth),
+                node.name,
+                kind,
+                sizes[id],
+                self.mark(id, &sizes)
+            ));
+            if opts.max_depth.is_none_or(|max| depth < max) {
+                let children = self.sorted_children(id, &sizes, opts);
+                stack.extend(children.into_iter().rev().map(|c| (c, depth + 1)));
+            }
+        }
+        out
+    }
+
+    /// like `du -h`, directories only and every one after its subdirectories,
+    /// the ones up to `SMALL_DIR` marked with *
+    pub fn render_du(&self, opts: &RenderOptions) -> String {
+        let sizes = self.sizes();
+        let mut lines = Vec::new();
+        // (dir, depth, whether its subdirectories are listed yet)
+        let mut stack = vec![(Fs::ROOT, 0, false)];
+        while let Some((id, depth, expanded)) = stack.pop() {
+            if expanded || opts.max_depth.is_some_and(|max| depth >= max) {
+                lines.push(id);
+                continue;
+            }
+            stack.push((id, depth, true));
+            let children = self.sorted_children(id, &sizes, opts);
+            // popped in order, so pushed in reverse
+            for c in children.into_iter().rev() {
+                if self.nodes[c].is_dir() {
+                    stack.push((c, depth + 1, false));
+                }
+            }
+        }
+        if opts.sort_by_size {
+            lines.sort_by(|a, b| sizes[*b].cmp(&sizes[*a]));
+        }
+
+        let mut out = String::new();
+        for id in lines {
+            out.push_str(&format!(
+                "{}\t{}{}\n",
+                human_size(sizes[id]),
+                self.path(id),
+                self.mark(id, &sizes)
+            ));
+        }
+        out
+    }
+}
+
+// like `du -h`, rounding up: 1023, 1.0K, 9.9K, 10K, 1.5M
+pub fn human_size(size: u64) -> String {
+    if size < 1024 {
+        return size.to_string();
+    }
+    let mut unit = 0;
+    let mut value = size as f64;
+    while value >= 1024.0 && unit < 4 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    let suffix = ["", "K", "M", "G", "T"][unit];
+    if value < 10.0 {
+        let tenths = (value * 10.0).ceil() / 10.0;
+        if tenths < 10.0 {
+            return format!("{:.1}{}", tenths, suffix);
+        }
+    }
+    format!("{}{}", value.ceil(), suffix)
+}
+
+pub fn report(filepath: &str) -> String {
+    let opts = RenderOptions {
+        sort_by_size: true,
+        max_depth: Some(1),
+    };
+    let fs = parse(filepath);
+    let (mut dirs, mut files, mut deepest) = (0, 0, 0);
+    for (id, depth) in fs.iter() {
+        if fs.node(id).is_dir() {
+            dirs += 1;
+        } else {
+            files += 1;
+        }
+        deepest = deepest.max(depth);
+    }
+    let mut out = format!(
+        "{} directories, {} files, nested {} deep\n\n{}\n{}\n",
+        dirs,
+        files,
+        deepest,
+        fs.render_tree(&opts),
+        fs.render_du(&opts)
+    );
+    match plan(&fs, 70000000, 30000000) {
+        Ok(plan) => {
+            out.push_str(&format!(
+                "{} free, {} more needed, {} directories would do\n",
+                plan.free,
+                plan.needed,
+                plan.candidates.len()
+            ));
+            for c in plan.minimal_set.iter() {
+                // going by the path, as the deletion would
+                let entries = fs.lookup(&c.path).map_or(0, |id| fs.children(id).len());
+                out.push_str(&format!(
+                    "delete {} ({}, {} entries)\n",
+                    c.path, c.size, entries
+                ));
+            }
+        }
+        Err(e) => out.push_str(&format!("{}\n", e)),
+    }
+    out
+}
+
+// `cd` from `cwd` to an absolute or relative `target`, creating directories
+// the transcript hasn't listed yet
+fn change_dir(fs: &mut Fs, cwd: NodeId, target: &str, line: usize) -> Result<NodeId, ShellError> {
+    let mut dir = if target.starts_with('/') {
+        Fs::ROOT
+    } else {
+        cwd
+    };
+    for name in target.split('/').filter(|name| !name.is_empty()) {
+        dir = match name {
+            "." => dir,
+            ".." => fs.node(dir).parent.unwrap_or(Fs::ROOT),
+            _ => match fs.child(dir, name) {
+                Some(id) if !fs.node(id).is_dir() => {
+                    return Err(ShellError::NotADirectory {
+                        line,
+                        path: fs.path(id),
+                    })
+                }
+                _ => fs.add_dir(dir, name),
+            },
+        };
+    }
+    Ok(dir)
+}
+
+/// replay a transcript, listing a directory again only updates it
+pub fn build(content: &str) -> Result<Fs, ShellError> {
+    let mut fs = Fs::new();
+    let mut cwd = Fs::ROOT;
+    // whether the lines that follow are the output of an `ls`
+    let mut listing = false;
+    for (line, t) in tokenize(content)? {
+        match t {
+            Token::Ls => listing = true,
+            Token::Cd(target) => {
+                listing = false;
+                cwd = change_dir(&mut fs, cwd, &target, line)?;
+            }
+            Token::Dir(_) | Token::FileStat { .. } if !listing => {
+                return Err(ShellError::UnexpectedOutput { line });
+            }
+            Token::Dir(name) => {
+                if let Some(id) = fs.child(cwd, &name) {
+                    if !fs.node(id).is_dir() {
+                        return Err(ShellError::Conflict {
+                            line,
+                            path: fs.path(id),
+                        });
+                    }
+                }
+                fs.add_dir(cwd, &name);
+            }
+            Token::FileStat { size, name } => {
+                if let Some(id) = fs.child(cwd, &name) {
+                    if fs.node(id).is_dir() {
+                        return Err(ShellError::Conflict {
+                            line,
+                            path: fs.path(id),
+                        });
+                    }
+                }
+                fs.add_file(cwd, &name, size);
+            }
+        }
+    }
+    Ok(fs)
+}
+
+pub fn parse(filepath: &str) -> Fs {
+    let content = fs::read_to_string(filepath).expect("expect file");
+    match build(&content) {
+        Ok(fs) => fs,
+        Err(e) => panic!("{}", e),
+    }
+}
+
+// the size of every directory
+fn dir_sizes(fs: &Fs) -> Vec<u64> {
+    let sizes = fs.sizes();
+    fs.dirs().map(|id| sizes[id]).collect()
+}
+
+fn calc_part1(dirs: &[u64]) -> u64 {
+    dirs.iter().filter(|size| **size <= SMALL_DIR).sum()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Candidate {
+    pub id: NodeId,
+    pub path: String,
+    pub size: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    pub free: u64,
+    // how much more has to be freed, 0 when there's enough already
+    pub needed: u64,
+    // every directory big enough on its own, smallest first
+    pub candidates: Vec<Candidate>,
+    // the fewest directories that are enough together, the smallest
+    // candidate when there is one
+    pub minimal_set: Vec<Candidate>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlanError {
+    DiskTooSmall { disk: u64, used: u64 },
+    // even deleting every directory doesn't free enough
+    NotEnough { needed: u64, deletable: u64 },
+}
+
+impl fmt::Display for PlanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlanError::DiskTooSmall { disk, used } => {
+                write!(f, "{} used on a disk of {}", used, disk)
+            }
+            PlanError::NotEnough { needed, deletable } => write!(
+                f,
+                "need to free {} but the directories only hold {}",
+                needed, deletable
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PlanError {}
+
+/// which directories to delete to get `required` free on a disk of
+/// `disk_size`. the root itself can't be deleted
+pub fn plan(fs: &Fs, disk_size: u64, required: u64) -> Result<Plan, PlanError> {
+    let sizes = fs.sizes();
+    let used = sizes[Fs::ROOT];
+    let free = disk_size.checked_sub(used).ok_or(PlanError::DiskTooSmall {
+        disk: disk_size,
+        used,
+    })?;
+    let needed = required.saturating_sub(free);
+    let candidate = |id| Candidate {
+        id,
+        path: fs.path(id),
+        size: sizes[id],
+    };
+
+    let mut candidates: Vec<Candidate> = fs
+        .dirs()
+        .filter(|id| *id != Fs::ROOT && sizes[*id] >= needed)
+        .map(candidate)
+        .collect();
+    candidates.sort_by(|a, b| a.size.cmp(&b.size).then_with(|| a.path.cmp(&b.path)));
+
+    let minimal_set = if needed == 0 {
+        Vec::new()
+    } else if let Some(smallest) = candidates.first() {
+        vec![smallest.clone()]
+    } else {
+        // any set of k directories that don't contain each other fits in the
+        // k biggest top level ones, so taking those biggest first needs the
+        // fewest
+        let mut top: Vec<NodeId> = fs
+            .children(Fs::ROOT)
+            .iter()
+            .copied()
+            .filter(|id| fs.node(*id).is_dir())
+            .collect();
+        top.sort_by(|a, b| sizes[*b].cmp(&sizes[*a]));
+        let mut set = Vec::new();
+        let mut freed = 0;
+        for id in top {
+            if freed >= needed {
+                break;
+            }
+            freed += sizes[id];
+            set.push(candidate(id));
+        }
+        if freed < needed {
+            return Err(PlanError::NotEnough {
+                needed,
+                deletable: freed,
+            });
+        }
+        set
+    };
+
+    Ok(Plan {
+        free,
+        needed,
+        candidates,
+        minimal_set,
+    })
+}
+
+pub fn part1(filepath: &str) -> u64 {
+    calc_part1(&dir_sizes(&parse(filepath)))
+}
+
+pub fn part2(filepath: &str) -> u64 {
+    match plan(&parse(filepath), 70000000, 30000000) {
+        Ok(plan) => match plan.candidates.first() {
+            Some(smallest) => smallest.size,
+            None => panic!("no single directory frees {}", plan.needed),
+        },
+        Err(e) => panic!("{}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(95437, part1("data/2022/day7-sample.txt"));
+        assert_eq!(1778099, part1("data/2022/day7.txt"));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(24933642, part2("data/2022/day7-sample.txt"));
+        assert_eq!(1623571, part2("data/2022/day7.txt"));
+    }
+
+    #[test]
+    fn test_fs() {
+        let fs = parse("data/2022/day7-sample.txt");
+        let sizes = fs.sizes();
+        let size_of = |path| sizes[fs.lookup(path).unwrap()];
+        assert_eq!(584, size_of("/a/e"));
+        assert_eq!(94853, size_of("/a"));
+        assert_eq!(24933642, size_of("/d"));
+        assert_eq!(48381165, size_of("/"));
+        assert_eq!(62596, size_of("/a/h.lst"));
+        assert_eq!(None, fs.lookup("/a/x"));
+
+        let e = fs.lookup("/a/e/").unwrap();
+        assert_eq!("/a/e", fs.path(e));
+        assert_eq!("/", fs.path(Fs::ROOT));
+        assert!(fs.node(e).is_dir());
+        assert_eq!(Some(e), fs.child(fs.lookup("/a").unwrap(), "e"));
+
+        let paths: Vec<(String, usize)> =
+            fs.iter().map(|(id, depth)| (fs.path(id), depth)).collect();
+        assert_eq!(14, paths.len());
+        assert_eq!(("/".to_owned(), 0), paths[0]);
+        assert_eq!(("/a".to_owned(), 1), paths[1]);
+        assert_eq!(("/a/e".to_owned(), 2), paths[2]);
+        assert_eq!(("/a/e/i".to_owned(), 3), paths[3]);
+        assert_eq!(("/d/k".to_owned(), 2), paths[13]);
+        assert_eq!(4, fs.dirs().count());
+    }
+
+    #[test]
+    fn test_shell() {
+        let transcript = "$ cd /
+$ ls
+dir a
+10 x
+$ cd a
+$ ls
+20 y
+$ cd /
+$ ls
+dir a
+10 x
+$ cd /a/b/../b
+$ ls
+30 z
+$ cd ../..
+$ cd a
+$ ls
+25 y
+$ cd .
+$ cd ..
+$ cd ..
+$ ls
+10 x
+";
+        let fs = build(transcript).unwrap();
+        let sizes = fs.sizes();
+        // listing / and a twice doesn't count them twice, the second listing
+        // of a updates y
+        assert_eq!(65, sizes[Fs::ROOT]);
+        assert_eq!(55, sizes[fs.lookup("/a").unwrap()]);
+        assert_eq!(30, sizes[fs.lookup("/a/b/z").unwrap()]);
+        assert_eq!(6, fs.iter().count());
+
+        assert_eq!(
+            Err(ShellError::Unsupported {
+                line: 2,
+                command: "rm -rf a".to_owned()
+            }),
+            build("$ cd /\n$ rm -rf a\n").map(|_| ())
+        );
+        assert_eq!(
+            Err(ShellError::UnexpectedOutput { line: 2 }),
+            build("$ cd /\n10 x\n").map(|_| ())
+        );
+        assert_eq!(
+            "line 4: /x isn't a directory",
+            build("$ cd /\n$ ls\n10 x\n$ cd x/y\n")
+                .unwrap_err()
+                .to_string()
+        );
+        assert_eq!(
+            Err(ShellError::Conflict {
+                line: 4,
+                path: "/x".to_owned()
+            }),
+            build("$ ls\n10 x\n$ ls\ndir x\n").map(|_| ())
+        );
+        assert_eq!(
+            Err(ShellError::Malformed {
+                line: 2,
+                text: "ten x".to_owned()
+            }),
+            build("$ ls\nten x\n").map(|_| ())
+        );
+    }
+
+    #[test]
+    fn test_plan() {
+        let fs = parse("data/2022/day7-sample.txt");
+        let plan = plan(&fs, 70000000, 30000000).unwrap();
+        assert_eq!((21618835, 8381165), (plan.free, plan.needed));
+        let candidates: Vec<(&str, u64)> = plan
+            .candidates
+            .iter()
+            .map(|c| (&c.path[..], c.size))
+            .collect();
+        assert_eq!(vec![("/d", 24933642)], candidates);
+        assert_eq!(plan.candidates, plan.minimal_set);
+
+        // enough space already, anything goes and nothing has to go
+        let plan = super::plan(&fs, 100000000, 30000000).unwrap();
+        assert_eq!(0, plan.needed);
+        assert_eq!(
+            vec!["/a/e", "/a", "/d"],
+            plan.candidates
+                .iter()
+                .map(|c| &c.path[..])
+                .collect::<Vec<_>>()
+        );
+        assert!(plan.minimal_set.is_empty());
+
+        // no single directory is enough, /d and /a together are
+        let plan = super::plan(&fs, 48381165, 24933642 + 1).unwrap();
+        assert!(plan.candidates.is_empty());
+        assert_eq!(
+            vec!["/d", "/a"],
+            plan.minimal_set
+                .iter()
+                .map(|c| &c.path[..])
+                .collect::<Vec<_>>()
+        );
+
+        assert_eq!(
+            Err(PlanError::NotEnough {
+                needed: 30000000,
+                deletable: 24933642 + 94853
+            }),
+            super::plan(&fs, 48381165, 30000000)
+        );
+        assert_eq!(
+            Err(PlanError::DiskTooSmall {
+                disk: 1000,
+                used: 48381165
+            }),
+            super::plan(&fs, 1000, 0)
+        );
+    }
+
+    #[test]
+    fn test_render_tree() {
+        let fs = parse("data/2022/day7-sample.txt");
+        let full = fs.render_tree(&RenderOptions::default());
+        assert_eq!(14, full.lines().count());
+        assert_eq!(
+            "- / (dir, size=48381165)\n  - a (dir, size=94853) *\n    - e (dir, size=584) *\n      - i (file, size=584)\n",
+            full.lines().take(4).map(|l| format!("{}\n", l)).collect::<String>()
+        );
+
+        let opts = RenderOptions {
+            sort_by_size: true,
+            max_depth: Some(1),
+        };
+        assert_eq!(
+            "- / (dir, size=48381165)
+  - d (dir, size=24933642)
+  - b.txt (file, size=14848514)
+  - c.dat (file, size=8504156)
+  - a (dir, size=94853) *
+",
+            fs.render_tree(&opts)
+        );
+    }
+
+    #[test]
+    fn test_render_du() {
+        let fs = parse("data/2022/day7-sample.txt");
+        assert_eq!(
+            "584\t/a/e *\n93K\t/a *\n24M\t/d\n47M\t/\n",
+            fs.render_du(&RenderOptions::default())
+        );
+        let opts = RenderOptions {
+            sort_by_size: true,
+            max_depth: Some(1),
+        };
+        assert_eq!("47M\t/\n24M\t/d\n93K\t/a *\n", fs.render_du(&opts));
+        let opts = RenderOptions {
+            sort_by_size: false,
+            max_depth: Some(0),
+        };
+        assert_eq!("47M\t/\n", fs.render_du(&opts));
+
+        assert_eq!("1023", human_size(1023));
+        assert_eq!("1.0K", human_size(1024));
+        assert_eq!("1.1K", human_size(1025));
+        assert_eq!("10K", human_size(10 * 1024 - 1));
+        assert_eq!("10K", human_size(10 * 1024));
+        assert_eq!("1.5M", human_size(3 * 512 * 1024));
+    }
+}
@@ -0,0 +1,398 @@
+use std::{fmt, fs};
+
+use crate::lint::{Grammar, Issue};
+
+pub const VERSION: &str = "2";
+
+pub const GRAMMAR: Grammar = Grammar {
+    lines: &["[0-9]+"],
+    check: Some(check_input),
+};
+
+fn check_input(input: &str) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    let width = input.lines().next().map_or(0, |l| l.len());
+    if width == 0 {
+        issues.push(Issue::file("empty forest"));
+    }
+    for (idx, line) in input.lines().enumerate() {
+        if line.len() != width {
+            issues.push(Issue::at(
+                idx + 1,
+                format!("{} trees, expected {}", line.len(), width),
+            ));
+        }
+    }
+    issues
+}
+
+/// row by row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    pub rows: usize,
+    pub cols: usize,
+    pub cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(rows: usize, cols: usize, value: T) -> Self {
+        Grid {
+            rows,
+            cols,
+            cells: vec![value; rows * cols],
+        }
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> &T {
+        &self.cells[row * self.cols + col]
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ForestError {
+    Empty,
+    // rows of the forest all have to be as wide as the first one
+    Ragged {
+        line: usize,
+        len: usize,
+        expected: usize,
+    },
+    NotATree {
+        line: usize,
+        col: usize,
+        found: char,
+    },
+}
+
+impl fmt::Display for ForestError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ForestError::Empty => write!(f, "empty forest"),
+            ForestError::Ragged {
+                line,
+                len,
+                expected,
+            } => write!(f, "line {}: {} trees, expected {}", line, len, expected),
+            ForestError::NotATree { line, col, found } => {
+                write!(f, "line {}, column {}: {:?} isn't a tree", line, col, found)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ForestError {}
+
+/// tree heights, any number of rows and columns
+pub fn parse(cnt: &str) -> Result<Grid<u8>, ForestError> {
+    let cols = cnt.lines().next().map_or(0, |l| l.len());
+    if cols == 0 {
+        return Err(ForestError::Empty);
+    }
+    let (mut rows, mut cells) = (0, Vec::new());
+    for (idx, line) in cnt.lines().enumerate() {
+        if line.len() != cols {
+            return Err(ForestError::Ragged {
+                line: idx + 1,
+                len: line.len(),
+                expected: cols,
+            });
+        }
+        for (col, b) in line.bytes().enumerate() {
+            if !b.is_ascii_digit() {
+                return Err(ForestError::NotATree {
+                    line: idx + 1,
+                    col: col + 1,
+                    found: b as char,
+                });
+            }
+            cells.push(b - b'0');
+        }
+        rows += 1;
+    }
+    Ok(Grid { rows, cols, cells })
+}
+
+/// what both parts need to know about every tree
+pub struct Survey {
+    // seen from outside the forest in at least one direction
+    pub visible: Grid<bool>,
+    // viewing distances in all four directions multiplied
+    pub scenic: Grid<u64>,
+}
+
+// walk one line of trees, `cells` being their indices in the order looked
+// along. `stack` keeps the trees not yet hidden behind a taller or equally
+// tall one, so the first tree at least as tall as the current one is on top
+// once the shorter ones are popped
+fn sweep(
+    heights: &Grid<u8>,
+    cells: impl Iterator<Item = usize>,
+    survey: &mut Survey,
+    stack: &mut Vec<(u8, usize)>,
+) {
+    stack.clear();
+    for (pos, idx) in cells.enumerate() {
+        let h = heights.cells[idx];
+        while stack.last().is_some_and(|(top, _)| *top < h) {
+            stack.pop();
+        }
+        let distance = match stack.last() {
+            Some((_, blocker)) => pos - blocker,
+            None => {
+                survey.visible.cells[idx] = true;
+                pos
+            }
+        };
+        survey.scenic.cells[idx] *= distance as u64;
+        stack.push((h, pos));
+    }
+}
+
+/// visibility and scenic scores of every tree in O(rows * cols)
+pub fn survey(heights: &Grid<u8>) -> Survey {
+    let (rows, cols) = (heights.rows, heights.cols);
+    let mut survey = Survey {
+        visible: Grid::new(rows, cols, false),
+        scenic: Grid::new(rows, cols, 1),
+    };
+    let mut stack = Vec::new();
+    for r in 0..rows {
+        let row = r * cols..(r + 1) * cols;
+        sweep(heights, row.clone(), &mut survey, &mut stack);
+        sweep(heights, row.rev(), &mut survey, &mut stack);
+    }
+    for c in 0..cols {
+        let col = (0..rows).map(|r| r * cols + c);
+        sweep(heights, col.clone(), &mut survey, &mut stack);
+        sweep(heights, col.rev(), &mut survey, &mut stack);
+    }
+    survey
+}
+
+pub fn survey_file(filepath: &str) -> Survey {
+    let cnt = fs::read_to_string(filepath).expect("expect file");
+    match parse(&cnt) {
+        Ok(heights) => survey(&heights),
+        Err(e) => panic!("{}", e),
+    }
+}
+
+// darkest to brightest
+const SHADES: &[u8] = b" .:-=+*#%@";
+
+/// scenic scores as characters, brighter is better, the best trees being `@`
+pub fn heatmap(scenic: &Grid<u64>) -> String {
+    let max = scenic.cells.iter().copied().max().unwrap_or(0).max(1);
+    let top = SHADES.len() as u64 - 1;
+    render(scenic, |score| {
+        // rounded up, so only a score of 0 is blank
+        SHADES[((score * top).div_ceil(max)) as usize] as char
+    })
+}
+
+/// `#` for the trees seen from outside the forest, `.` for the hidden ones
+pub fn mask(visible: &Grid<bool>) -> String {
+    render(visible, |v| if *v { '#' } else { '.' })
+}
+
+fn render<T>(grid: &Grid<T>, cell: impl Fn(&T) -> char) -> String {
+    let mut out = String::with_capacity(grid.rows * (grid.cols + 1));
+    for row in grid.cells.chunks(grid.cols.max(1)) {
+        out.extend(row.iter().map(&cell));
+        out.push('\n');
+    }
+    out
+}
+
+/// a plain PPM (P3) image, one pixel per tree: the scenic score in shades of
+/// red, drawn over green for the visible trees and black for the hidden ones
+pub fn ppm(survey: &Survey) -> String {
+    let (rows, cols) = (survey.scenic.rows, survey.scenic.cols);
+    let max = survey
+        .scenic
+        .cells
+        .iter()
+        .copied()
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    let mut out = format!("P3\n{} {}\n255\n", cols, rows);
+    for r in 0..rows {
+        let row: Vec<String> = (0..cols)
+            .map(|c| {
+                let red = survey.scenic.get(r, c) * 255 / max;
+                let green = if *survey.visible.get(r, c) { 160 } else { 0 };
+                format!("{} {} 0", red, green)
+            })
+            .collect();
+        out.push_str(&row.join(" "));
+        out.push('\n');
+    }
+    out
+}
+
+pub fn report(filepath: &str) -> String {
+    let survey = survey_file(filepath);
+    let scenic = &survey.scenic;
+    let best = scenic.cells.iter().copied().max().unwrap_or(0);
+    let spots: Vec<String> = (0..scenic.cells.len())
+        .filter(|i| scenic.cells[*i] == best)
+        .map(|i| format!("({}, {})", i / scenic.cols, i % scenic.cols))
+        .collect();
+    let visible = survey.visible.cells.iter().filter(|v| **v).count();
+    format!(
+        "{} rows, {} columns, {} trees visible\nbest scenic score {} at {}\n\nscenic scores:\n{}\nvisible trees:\n{}",
+        scenic.rows,
+        scenic.cols,
+        visible,
+        best,
+        spots.join(", "),
+        heatmap(scenic),
+        mask(&survey.visible)
+    )
+}
+
+pub fn part1(filepath: &str) -> usize {
+    survey_file(filepath)
+        .visible
+        .cells
+        .iter()
+        .filter(|v| **v)
+        .count()
+}
+
+pub fn part2(filepath: &str) -> u64 {
+    survey_file(filepath)
+        .scenic
+        .cells
+        .into_iter()
+        .max()
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(21, part1("data/2022/day8-sample.txt"));
+        assert_eq!(1698, part1("data/2022/day8.txt"));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(8, part2("data/2022/day8-sample.txt"));
+        assert_eq!(672280, part2("data/2022/day8.txt"));
+    }
+
+    #[test]
+    fn test_survey() {
+        let cnt = fs::read_to_string("data/2022/day8-sample.txt").unwrap();
+        let survey = survey(&parse(&cnt).unwrap());
+        // the 5 in the middle of the second row, and the 5 of the fourth
+        assert_eq!(4, *survey.scenic.get(1, 2));
+        assert_eq!(8, *survey.scenic.get(3, 2));
+        assert_eq!(0, *survey.scenic.get(0, 3));
+        assert!(*survey.visible.get(1, 1));
+        assert!(!*survey.visible.get(1, 3));
+        assert!(!*survey.visible.get(2, 2));
+        assert!(*survey.visible.get(4, 4));
+    }
+
+    // looks in all four directions tree by tree, for checking the sweeps
+    fn naive(heights: &Grid<u8>) -> Survey {
+        let (rows, cols) = (heights.rows, heights.cols);
+        let mut visible = Grid::new(rows, cols, false);
+        let mut scenic = Grid::new(rows, cols, 1);
+        for r in 0..rows {
+            for c in 0..cols {
+                let h = *heights.get(r, c);
+                let lines: [Vec<(usize, usize)>; 4] = [
+                    (0..r).rev().map(|r| (r, c)).collect(),
+                    (r + 1..rows).map(|r| (r, c)).collect(),
+                    (0..c).rev().map(|c| (r, c)).collect(),
+                    (c + 1..cols).map(|c| (r, c)).collect(),
+                ];
+                let idx = r * cols + c;
+                for line in lines {
+                    match line.iter().position(|(r, c)| *heights.get(*r, *c) >= h) {
+                        Some(pos) => scenic.cells[idx] *= pos as u64 + 1,
+                        None => {
+                            visible.cells[idx] = true;
+                            scenic.cells[idx] *= line.len() as u64;
+                        }
+                    }
+                }
+            }
+        }
+        Survey { visible, scenic }
+    }
+
+    #[test]
+    fn test_rectangular() {
+        let wide = "3037325\n2551227\n6533209\n";
+        let heights = parse(wide).unwrap();
+        assert_eq!((3, 7), (heights.rows, heights.cols));
+        let survey = survey(&heights);
+        // the 5 at (1, 2) sees 1 up, 1 down, 1 left and 4 right
+        assert_eq!(4, *survey.scenic.get(1, 2));
+        assert_eq!("#######\n###..##\n#######\n", mask(&survey.visible));
+
+        let tall = "30\n25\n65\n33\n35\n";
+        let survey = super::survey(&parse(tall).unwrap());
+        assert_eq!("##\n##\n##\n##\n##\n", mask(&survey.visible));
+
+        for cnt in [wide, tall, "1\n", "12345\n", "1\n2\n3\n"] {
+            let heights = parse(cnt).unwrap();
+            let (fast, slow) = (super::survey(&heights), naive(&heights));
+            assert_eq!(slow.visible, fast.visible);
+            assert_eq!(slow.scenic, fast.scenic);
+        }
+        let cnt = fs::read_to_string("data/2022/day8.txt").unwrap();
+        let heights = parse(&cnt).unwrap();
+        let (fast, slow) = (super::survey(&heights), naive(&heights));
+        assert_eq!(slow.visible, fast.visible);
+        assert_eq!(slow.scenic, fast.scenic);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(Err(ForestError::Empty), parse(""));
+        assert_eq!(
+            Err(ForestError::Ragged {
+                line: 2,
+                len: 2,
+                expected: 3
+            }),
+            parse("123\n45\n")
+        );
+        assert_eq!(
+            Err(ForestError::NotATree {
+                line: 1,
+                col: 2,
+                found: 'x'
+            }),
+            parse("1x3\n")
+        );
+    }
+
+    #[test]
+    fn test_heatmap() {
+        let cnt = fs::read_to_string("data/2022/day8-sample.txt").unwrap();
+        let survey = survey(&parse(&cnt).unwrap());
+        assert_eq!(
+            "     \n :+: \n #:- \n :@= \n     \n",
+            heatmap(&survey.scenic)
+        );
+        assert_eq!("#####\n###.#\n##.##\n#.#.#\n#####\n", mask(&survey.visible));
+        let image = ppm(&survey);
+        assert!(image.starts_with("P3\n5 5\n255\n0 160 0 "));
+        assert_eq!(3 + 5, image.lines().count());
+        // the best spot is the brightest
+        assert_eq!(
+            Some("0 160 0 31 0 0 255 160 0 95 0 0 0 160 0"),
+            image.lines().nth(6)
+        );
+    }
+}
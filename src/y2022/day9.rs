@@ -0,0 +1,305 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::fs;
+use std::str::{self, FromStr};
+
+use crate::lint::Grammar;
+
+pub const VERSION: &str = "2";
+
+pub const GRAMMAR: Grammar = Grammar {
+    lines: &["[UDLR] {uint}"],
+    check: None,
+};
+
+extern crate test;
+
+#[derive(Hash, Eq, PartialEq, Clone, Copy, Debug)]
+pub struct Position {
+    pub x: i32,
+    pub y: i32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dir {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Dir {
+    fn delta(self) -> (i32, i32) {
+        match self {
+            Dir::Up => (0, 1),
+            Dir::Down => (0, -1),
+            Dir::Left => (-1, 0),
+            Dir::Right => (1, 0),
+        }
+    }
+}
+
+/// one line of the input, e.g. "R 4"
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub dir: Dir,
+    pub steps: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseMoveError(String);
+
+impl fmt::Display for ParseMoveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid move {:?}, expected U/D/L/R and a number",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for ParseMoveError {}
+
+impl FromStr for Move {
+    type Err = ParseMoveError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseMoveError(s.to_owned());
+        let (dir, steps) = s.split_once(' ').ok_or_else(err)?;
+        let dir = match dir {
+            "U" => Dir::Up,
+            "D" => Dir::Down,
+            "L" => Dir::Left,
+            "R" => Dir::Right,
+            _ => return Err(err()),
+        };
+        let steps = steps.parse().map_err(|_| err())?;
+        Ok(Move { dir, steps })
+    }
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let dir = match self.dir {
+            Dir::Up => 'U',
+            Dir::Down => 'D',
+            Dir::Left => 'L',
+            Dir::Right => 'R',
+        };
+        write!(f, "{} {}", dir, self.steps)
+    }
+}
+
+/// every move of the input, or the first bad one with its 1-based line
+pub fn parse(cnt: &str) -> Result<Vec<Move>, (usize, ParseMoveError)> {
+    cnt.lines()
+        .enumerate()
+        .map(|(idx, line)| line.parse().map_err(|e| (idx + 1, e)))
+        .collect()
+}
+
+fn move_tail(head: &Position, tail: &mut Position) {
+    let x_diff = head.x - tail.x;
+    let y_diff = head.y - tail.y;
+
+    if x_diff.abs() == 2 || y_diff.abs() == 2 {
+        tail.x += x_diff.signum();
+        tail.y += y_diff.signum();
+    }
+}
+
+/// knots all starting at the same place, the head being knot 0
+pub struct Rope {
+    knots: Vec<Position>,
+}
+
+impl Rope {
+    pub fn new(len: usize) -> Self {
+        assert!(len > 0, "a rope needs at least one knot");
+        Rope {
+            knots: vec![Position { x: 0, y: 0 }; len],
+        }
+    }
+
+    pub fn knot(&self, k: usize) -> Position {
+        self.knots[k]
+    }
+
+    /// move the head by one, and the rest of the knots after it
+    pub fn step(&mut self, dir: Dir) {
+        let (dx, dy) = dir.delta();
+        self.knots[0].x += dx;
+        self.knots[0].y += dy;
+        for i in 1..self.knots.len() {
+            let head = self.knots[i - 1];
+            let before = self.knots[i];
+            move_tail(&head, &mut self.knots[i]);
+            // if tail is not changed afer move_tail
+            // we can break out the loop to skip calculation for nodes after this one
+            if self.knots[i] == before {
+                break;
+            }
+        }
+    }
+}
+
+/// every position knot `k` of a rope of `len` knots has been at
+pub fn trail(moves: &[Move], len: usize, k: usize) -> HashSet<Position> {
+    assert!(k < len, "knot {} isn't on a rope of {} knots", k, len);
+    let mut rope = Rope::new(len);
+    let mut visited = HashSet::new();
+    visited.insert(rope.knot(k));
+    for m in moves {
+        for _i in 0..m.steps {
+            rope.step(m.dir);
+            visited.insert(rope.knot(k));
+        }
+    }
+    visited
+}
+
+fn visited_file(filepath: &str, len: usize) -> u32 {
+    let cnt = fs::read_to_string(filepath).expect("expect file");
+    match parse(&cnt) {
+        Ok(moves) => trail(&moves, len, len - 1).len() as u32,
+        Err((line, e)) => panic!("line {}: {}", line, e),
+    }
+}
+
+pub fn report(filepath: &str) -> String {
+    let cnt = fs::read_to_string(filepath).expect("expect file");
+    let moves = match parse(&cnt) {
+        Ok(moves) => moves,
+        Err((line, e)) => return format!("line {}: {}\n", line, e),
+    };
+    let steps: u32 = moves.iter().map(|m| m.steps).sum();
+    let mut out = format!("{} moves, {} steps\n", moves.len(), steps);
+    for k in 0..10 {
+        out.push_str(&format!(
+            "knot {} of 10 visited {} positions\n",
+            k,
+            trail(&moves, 10, k).len()
+        ));
+    }
+    out
+}
+
+pub fn part1(filepath: &str) -> u32 {
+    visited_file(filepath, 2)
+}
+
+pub fn part2(filepath: &str) -> u32 {
+    // uncomment these lines to print the viz
+    // let cnt = fs::read_to_string(filepath).unwrap();
+    // viz(&trail(&parse(&cnt).unwrap(), 10, 9));
+    visited_file(filepath, 10)
+}
+
+#[allow(dead_code)]
+fn viz(visited: &HashSet<Position>) {
+    let iter = visited.iter();
+    let mut x_bound = (-1, 1);
+    let mut y_bound = (-1, 1);
+    for i in iter {
+        if i.x < x_bound.0 {
+            x_bound.0 = i.x;
+        } else if i.x > x_bound.1 {
+            x_bound.1 = i.x;
+        }
+        if i.y < y_bound.0 {
+            y_bound.0 = i.y;
+        } else if i.y > y_bound.1 {
+            y_bound.1 = i.y;
+        }
+    }
+
+    let mut grid = Vec::new();
+    let mut row = Vec::new();
+
+    for _x in x_bound.0..=x_bound.1 {
+        row.push(b'.');
+    }
+
+    for _y in y_bound.0..=y_bound.1 {
+        grid.push(row.clone())
+    }
+
+    for i in visited.iter() {
+        let row = y_bound.1 - i.y;
+        let col = i.x - x_bound.0;
+        grid[row as usize][col as usize] = b'#';
+    }
+
+    for r in grid {
+        let line = &r[..];
+        let s = str::from_utf8(line).unwrap();
+        println!("{}", s);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test::Bencher;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(13, part1("data/2022/day9-sample.txt"));
+        assert_eq!(6464, part1("data/2022/day9.txt"));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(36, part2("data/2022/day9-sample2.txt"));
+        assert_eq!(2604, part2("data/2022/day9.txt"));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            Ok(Move {
+                dir: Dir::Left,
+                steps: 12
+            }),
+            "L 12".parse()
+        );
+        assert_eq!("U 3", "U 3".parse::<Move>().unwrap().to_string());
+        assert!("X 3".parse::<Move>().is_err());
+        assert!("R".parse::<Move>().is_err());
+        assert!("R -1".parse::<Move>().is_err());
+        assert_eq!(2, parse("R 1\nR one\nL 2\n").unwrap_err().0);
+    }
+
+    #[test]
+    fn test_rope() {
+        let cnt = fs::read_to_string("data/2022/day9-sample.txt").unwrap();
+        let moves = parse(&cnt).unwrap();
+        // the head goes everywhere, a single knot is only a head
+        assert_eq!(trail(&moves, 1, 0), trail(&moves, 10, 0));
+        assert_eq!(13, trail(&moves, 2, 1).len());
+        assert_eq!(1, trail(&moves, 10, 9).len());
+        // a knot only follows the ones before it, however long the rope
+        for k in 0..5 {
+            assert_eq!(trail(&moves, k + 1, k), trail(&moves, 20, k));
+        }
+
+        let cnt = fs::read_to_string("data/2022/day9-sample2.txt").unwrap();
+        let moves = parse(&cnt).unwrap();
+        assert_eq!(36, trail(&moves, 10, 9).len());
+
+        let mut rope = Rope::new(3);
+        rope.step(Dir::Right);
+        rope.step(Dir::Right);
+        rope.step(Dir::Up);
+        rope.step(Dir::Up);
+        assert_eq!(Position { x: 2, y: 2 }, rope.knot(0));
+        assert_eq!(Position { x: 2, y: 1 }, rope.knot(1));
+        assert_eq!(Position { x: 1, y: 1 }, rope.knot(2));
+    }
+
+    #[bench]
+    fn bench_part2(b: &mut Bencher) {
+        b.iter(|| part2("data/2022/day9.txt"));
+    }
+}
@@ -0,0 +1,210 @@
+use crate::lint::Grammar;
+
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day2;
+pub mod day20;
+pub mod day21;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+// day, part and the input it runs on by default
+pub const PUZZLES: [(u32, u32, &str); 42] = [
+    (1, 1, "data/2022/day1.txt"),
+    (1, 2, "data/2022/day1.txt"),
+    (2, 1, "data/2022/day2.txt"),
+    (2, 2, "data/2022/day2.txt"),
+    (3, 1, "data/2022/day3.txt"),
+    (3, 2, "data/2022/day3.txt"),
+    (4, 1, "data/2022/day4.txt"),
+    (4, 2, "data/2022/day4.txt"),
+    (5, 1, "data/2022/day5.txt"),
+    (5, 2, "data/2022/day5.txt"),
+    (6, 1, "data/2022/day6.txt"),
+    (6, 2, "data/2022/day6.txt"),
+    (7, 1, "data/2022/day7.txt"),
+    (7, 2, "data/2022/day7.txt"),
+    (8, 1, "data/2022/day8.txt"),
+    (8, 2, "data/2022/day8.txt"),
+    (9, 1, "data/2022/day9.txt"),
+    (9, 2, "data/2022/day9.txt"),
+    (10, 1, "data/2022/day10-sample.txt"),
+    (10, 2, "data/2022/day10.txt"),
+    (11, 1, "data/2022/day11.txt"),
+    (11, 2, "data/2022/day11.txt"),
+    (12, 1, "data/2022/day12.txt"),
+    (12, 2, "data/2022/day12.txt"),
+    (13, 1, "data/2022/day13.txt"),
+    (13, 2, "data/2022/day13.txt"),
+    (14, 1, "data/2022/day14.txt"),
+    (14, 2, "data/2022/day14.txt"),
+    (15, 1, "data/2022/day15.txt"),
+    (15, 2, "data/2022/day15.txt"),
+    (16, 1, "data/2022/day16-sample.txt"),
+    (16, 2, "data/2022/day16.txt"),
+    (17, 1, "data/2022/day17.txt"),
+    (17, 2, "data/2022/day17-sample.txt"),
+    (18, 1, "data/2022/day18-sample.txt"),
+    (18, 2, "data/2022/day18.txt"),
+    (19, 1, "data/2022/day19-sample.txt"),
+    (19, 2, "data/2022/day19-sample.txt"),
+    (20, 1, "data/2022/day20.txt"),
+    (20, 2, "data/2022/day20.txt"),
+    (21, 1, "data/2022/day21.txt"),
+    (21, 2, "data/2022/day21.txt"),
+];
+
+pub fn solve(day: u32, part: u32, filepath: &str) -> Option<String> {
+    let answer = match (day, part) {
+        (1, 1) => day1::part1(filepath).to_string(),
+        (1, 2) => day1::part2(filepath).to_string(),
+        (2, 1) => day2::part1(filepath).to_string(),
+        (2, 2) => day2::part2(filepath).to_string(),
+        (3, 1) => day3::part1(filepath).to_string(),
+        (3, 2) => day3::part2(filepath).to_string(),
+        (4, 1) => day4::part1(filepath).to_string(),
+        (4, 2) => day4::part2(filepath).to_string(),
+        (5, 1) => day5::part1(filepath).to_string(),
+        (5, 2) => day5::part2(filepath).to_string(),
+        (6, 1) => day6::part1(filepath).to_string(),
+        (6, 2) => day6::part2(filepath).to_string(),
+        (7, 1) => day7::part1(filepath).to_string(),
+        (7, 2) => day7::part2(filepath).to_string(),
+        (8, 1) => day8::part1(filepath).to_string(),
+        (8, 2) => day8::part2(filepath).to_string(),
+        (9, 1) => day9::part1(filepath).to_string(),
+        (9, 2) => day9::part2(filepath).to_string(),
+        (10, 1) => day10::part1(filepath).to_string(),
+        (10, 2) => day10::part2(filepath).to_string(),
+        (11, 1) => day11::part1(filepath).to_string(),
+        (11, 2) => day11::part2(filepath).to_string(),
+        (12, 1) => day12::part1(filepath).to_string(),
+        (12, 2) => day12::part2(filepath).to_string(),
+        (13, 1) => day13::part1(filepath).to_string(),
+        (13, 2) => day13::part2(filepath).to_string(),
+        (14, 1) => day14::part1(filepath).to_string(),
+        (14, 2) => day14::part2(filepath).to_string(),
+        (15, 1) => day15::part1(filepath, 2000000).to_string(),
+        (15, 2) => format!("{:?}", day15::part2(filepath, 4000000)),
+        (16, 1) => day16::part1(filepath).to_string(),
+        (16, 2) => format!("{:?}", day16::part2(filepath)),
+        (17, 1) => format!("{:?}", day17::part1(filepath)),
+        (17, 2) => format!("{:?}", day17::part2(filepath)),
+        (18, 1) => format!("{:?}", day18::part1(filepath)),
+        (18, 2) => format!("{:?}", day18::part2(filepath)),
+        (19, 1) => format!("{:?}", day19::part1(filepath)),
+        (19, 2) => format!("{:?}", day19::part2(filepath)),
+        (20, 1) => format!("{:?}", day20::solve(1, filepath)),
+        (20, 2) => format!("{:?}", day20::solve(2, filepath)),
+        (21, 1) => format!("{:?}", day21::solve(filepath).0),
+        (21, 2) => format!("{:?}", day21::solve(filepath).1),
+        _ => return None,
+    };
+    Some(answer)
+}
+
+pub fn grammar(day: u32) -> Option<&'static Grammar> {
+    let grammar = match day {
+        1 => &day1::GRAMMAR,
+        2 => &day2::GRAMMAR,
+        3 => &day3::GRAMMAR,
+        4 => &day4::GRAMMAR,
+        5 => &day5::GRAMMAR,
+        6 => &day6::GRAMMAR,
+        7 => &day7::GRAMMAR,
+        8 => &day8::GRAMMAR,
+        9 => &day9::GRAMMAR,
+        10 => &day10::GRAMMAR,
+        11 => &day11::GRAMMAR,
+        12 => &day12::GRAMMAR,
+        13 => &day13::GRAMMAR,
+        14 => &day14::GRAMMAR,
+        15 => &day15::GRAMMAR,
+        16 => &day16::GRAMMAR,
+        17 => &day17::GRAMMAR,
+        18 => &day18::GRAMMAR,
+        19 => &day19::GRAMMAR,
+        20 => &day20::GRAMMAR,
+        21 => &day21::GRAMMAR,
+        _ => return None,
+    };
+    Some(grammar)
+}
+
+// parts whose answer is printed on the way, e.g. the letters of day 10 part
+// 2, what they return is only a placeholder
+pub fn printed_only(day: u32, part: u32) -> bool {
+    matches!((day, part), (10, 2))
+}
+
+// every day declares a `VERSION` to bump whenever its answers could change,
+// see src/cache.rs
+pub fn version(day: u32, part: u32) -> Option<&'static str> {
+    // a cached run would lose what's printed
+    if printed_only(day, part) {
+        return None;
+    }
+    let version = match day {
+        1 => day1::VERSION,
+        2 => day2::VERSION,
+        3 => day3::VERSION,
+        4 => day4::VERSION,
+        5 => day5::VERSION,
+        6 => day6::VERSION,
+        7 => day7::VERSION,
+        8 => day8::VERSION,
+        9 => day9::VERSION,
+        10 => day10::VERSION,
+        11 => day11::VERSION,
+        12 => day12::VERSION,
+        13 => day13::VERSION,
+        14 => day14::VERSION,
+        15 => day15::VERSION,
+        16 => day16::VERSION,
+        17 => day17::VERSION,
+        18 => day18::VERSION,
+        19 => day19::VERSION,
+        20 => day20::VERSION,
+        21 => day21::VERSION,
+        _ => return None,
+    };
+    Some(version)
+}
+
+// a human readable breakdown of a day's input, for the days that have one
+pub fn report(day: u32, filepath: &str) -> Option<String> {
+    let report = match day {
+        1 => day1::report(&day1::totals(filepath), 3),
+        2 => day2::report(filepath),
+        4 => day4::report(filepath),
+        5 => day5::report(filepath),
+        6 => day6::report(filepath),
+        7 => day7::report(filepath),
+        8 => day8::report(filepath),
+        9 => day9::report(filepath),
+        _ => return None,
+    };
+    Some(report)
+}
+
+// a plain PPM picture of a day's input, for the days that can draw one
+pub fn ppm(day: u32, filepath: &str) -> Option<String> {
+    match day {
+        8 => Some(day8::ppm(&day8::survey_file(filepath))),
+        _ => None,
+    }
+}